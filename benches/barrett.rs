@@ -0,0 +1,47 @@
+// Barrett reduction against the `%` operator, over products of two reduced values
+//
+//     cargo bench --bench barrett
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use cryptography::exercises::barrett::BarrettField;
+
+const PRIME: u32 = 4_294_967_291;
+
+fn products() -> Vec<u64> {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+
+    (0..1024)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % PRIME as u64) * ((state >> 32) % PRIME as u64)
+        })
+        .collect()
+}
+
+fn reduction(c: &mut Criterion) {
+    let field = BarrettField::new(PRIME).unwrap();
+    let products = products();
+    let mut group = c.benchmark_group("reduce 1024 products");
+
+    group.bench_function("remainder", |b| {
+        b.iter(|| {
+            let prime = black_box(PRIME as u64);
+            products.iter().map(|&x| x % prime).sum::<u64>()
+        })
+    });
+    group.bench_function("barrett", |b| {
+        b.iter(|| {
+            let field = black_box(field);
+            products.iter().map(|&x| field.reduce(x)).sum::<u64>()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, reduction);
+criterion_main!(benches);
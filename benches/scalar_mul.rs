@@ -0,0 +1,45 @@
+// Jacobian `scalar_mul_big` against affine double-and-add, which inverts a field element
+// on every doubling and addition instead of once at the end
+//
+//     cargo bench --bench scalar_mul
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use cryptography::exercises::s256::S256Point;
+use num_bigint::BigUint;
+
+fn affine_scalar_mul(point: &S256Point, scalar: &BigUint) -> S256Point {
+    let mut product = S256Point::infinity(point.a.clone(), point.b.clone());
+
+    for bit in (0..scalar.bits()).rev() {
+        product = product.double().unwrap();
+
+        if scalar.bit(bit) {
+            product = (product + point.clone()).unwrap();
+        }
+    }
+
+    product
+}
+
+fn scalar_mul(c: &mut Criterion) {
+    let g = S256Point::generator();
+    let scalar = S256Point::order() - BigUint::from(12345_u32);
+    let mut group = c.benchmark_group("256-bit scalar multiplication");
+
+    assert_eq!(affine_scalar_mul(&g, &scalar), g.scalar_mul_big(&scalar));
+
+    group.sample_size(10);
+    group.bench_function("affine", |b| {
+        b.iter(|| affine_scalar_mul(black_box(&g), black_box(&scalar)))
+    });
+    group.bench_function("jacobian", |b| {
+        b.iter(|| black_box(&g).scalar_mul_big(black_box(&scalar)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, scalar_mul);
+criterion_main!(benches);
@@ -0,0 +1,83 @@
+// w-NAF scalar multiplication against plain double-and-add, on y² = x³ + 7 over
+// 𝔽(2³¹ - 1). The point additions each one does are counted and printed first
+//
+//     cargo bench --bench wnaf
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use cryptography::exercises::{
+    ec_point::{wnaf, Point},
+    finite_field::Fp,
+};
+
+const PRIME: u32 = 2_147_483_647;
+
+fn scalars() -> Vec<u32> {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+
+    (0..256)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 32) as u32
+        })
+        .collect()
+}
+
+fn scalar_mul(c: &mut Criterion) {
+    let a = Fp::new(0, PRIME).unwrap();
+    let b = Fp::new(7, PRIME).unwrap();
+    let point = (1..)
+        .find_map(|x| Point::from_x(Fp::new(x, PRIME).unwrap(), a, b).ok())
+        .unwrap()
+        .0;
+    let scalars = scalars();
+
+    let double_and_add: u32 = scalars.iter().map(|scalar| scalar.count_ones()).sum();
+    println!("double-and-add: {} additions", double_and_add);
+    for width in 2..=5 {
+        let table = (1_u32 << (width - 2)) - 1;
+        let digits: u32 = scalars
+            .iter()
+            .map(|&scalar| {
+                wnaf(scalar, width)
+                    .unwrap()
+                    .iter()
+                    .filter(|&&d| d != 0)
+                    .count() as u32
+            })
+            .sum();
+        println!(
+            "w-NAF, w = {}: {} additions ({} per scalar for the table)",
+            width,
+            digits + table * scalars.len() as u32,
+            table
+        );
+    }
+
+    let mut group = c.benchmark_group("256 32-bit scalar multiplications");
+
+    group.bench_function("double-and-add", |bencher| {
+        bencher.iter(|| {
+            for &scalar in &scalars {
+                black_box(point.scalar_mul(black_box(scalar)));
+            }
+        })
+    });
+    for width in [2, 3] {
+        group.bench_function(format!("w-NAF, w = {}", width), |bencher| {
+            bencher.iter(|| {
+                for &scalar in &scalars {
+                    black_box(point.scalar_mul_wnaf(black_box(scalar), width).unwrap());
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, scalar_mul);
+criterion_main!(benches);
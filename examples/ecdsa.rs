@@ -0,0 +1,126 @@
+// ECDSA sign/verify end to end, over the toy curve found in `extension_fields::compute`;
+// the public key is printed as SEC and the signature as DER
+//
+// cargo run --example ecdsa
+
+use cryptography::exercises::{ec_point::Point, ecdsa::Signature, finite_field::Fp};
+use num_bigint::BigUint;
+
+/*
+ * Curve: y² = x³ - x - 1 over 𝔽157, #E(𝔽157) = 158 = 2 · 79
+ * G = (39, 54) generates the subgroup of prime order N = 79, so scalars
+ * (private keys, nonces, signature components) live in 𝔽79
+ */
+const P: u32 = 157;
+const N: u32 = 79;
+
+fn curve_a() -> Fp {
+    Fp::new(-1, P).unwrap()
+}
+
+fn curve_b() -> Fp {
+    Fp::new(-1, P).unwrap()
+}
+
+pub fn generator() -> Point<Fp> {
+    Point::new(
+        curve_a(),
+        curve_b(),
+        Some(Fp::new(39, P).unwrap()),
+        Some(Fp::new(54, P).unwrap()),
+    )
+    .unwrap()
+}
+
+pub fn scalar(value: i64) -> Fp {
+    Fp::new(value, N).unwrap()
+}
+
+// stand-in for a real hash: folds the message bytes into a scalar
+pub fn toy_hash(message: &[u8]) -> Fp {
+    let digest = message
+        .iter()
+        .fold(0_i64, |acc, &byte| (acc * 31 + byte as i64) % N as i64);
+
+    scalar(digest)
+}
+
+// the x coordinate of a point, reduced into the scalar field
+fn x_mod_n(point: Point<Fp>) -> Fp {
+    scalar(point.x.unwrap().num as i64)
+}
+
+/*
+ * r = (k·G).x (mod N)
+ * s = (z + r·e) / k (mod N)
+ *
+ * nonces giving r = 0 or s = 0 are skipped
+ */
+pub fn sign(secret: Fp, z: Fp, mut k: u32) -> (Fp, Fp) {
+    loop {
+        let nonce_point = generator().scalar_mul(k);
+
+        if !nonce_point.is_infinity() {
+            let r = x_mod_n(nonce_point);
+            let s = (z + r * secret) / scalar(k as i64);
+
+            if r.num != 0 && s.num != 0 {
+                return (r, s);
+            }
+        }
+
+        k = k % (N - 1) + 1;
+    }
+}
+
+/*
+ * u = z/s, v = r/s (mod N)
+ * valid iff (u·G + v·public_key).x == r (mod N)
+ */
+fn verify(public_key: Point<Fp>, z: Fp, (r, s): (Fp, Fp)) -> bool {
+    let u = z / s;
+    let v = r / s;
+
+    match generator().scalar_mul(u.num) + public_key.scalar_mul(v.num) {
+        Ok(total) if !total.is_infinity() => x_mod_n(total) == r,
+        _ => false,
+    }
+}
+
+/*
+ * The public key as compressed SEC and the signature as DER, both in hex. The toy
+ * coordinates take a single byte, so the SEC is 2 bytes rather than 33
+ */
+pub fn encode(public_key: &Point<Fp>, (r, s): (Fp, Fp)) -> (String, String) {
+    let signature = Signature::new(BigUint::from(r.num), BigUint::from(s.num));
+
+    (public_key.to_hex(true), signature.to_string())
+}
+
+pub fn main() {
+    let secret = scalar(23);
+    let public_key = generator().scalar_mul(secret.num);
+
+    println!("G               {}", generator());
+    println!("private key  e  {}", secret.num);
+    println!("public key   P  {}", public_key);
+
+    let message = b"Programming Bitcoin";
+    let z = toy_hash(message);
+    let signature = sign(secret, z, 42);
+
+    let (sec, der) = encode(&public_key, signature);
+
+    println!("message hash z  {}", z.num);
+    println!("public key SEC  {}", sec);
+    println!("signature DER   {}", der);
+
+    let is_valid = verify(public_key, z, signature);
+    println!("valid           {}", is_valid);
+    assert!(is_valid);
+
+    let tampered = toy_hash(b"Programming Ethereum");
+    let is_tampered_valid = verify(public_key, tampered, signature);
+    println!("tampered valid  {}", is_tampered_valid);
+    assert!(!is_tampered_valid);
+}
@@ -0,0 +1,112 @@
+// Book: Programming Bitcoin: Learn How to Program Bitcoin from Scratch by Jimmy song
+// Chapter 3: Elliptic Curve Cryptography — the full point addition (Cayley) table of a small curve
+//
+// cargo run -- table --prime 5 --a 1 --b 1
+
+use crate::exercises::{
+    ec_point::{is_non_singular, Point},
+    finite_field::Fp,
+};
+
+/*
+ * Every point of y² = x³ + ax + b over 𝔽prime, by trying all (x, y) pairs, with the
+ * point at infinity first. Only meant for small teaching fields: O(prime²)
+ */
+pub fn curve_points(prime: u32, a: i64, b: i64) -> Result<Vec<Point<Fp>>, String> {
+    let a = Fp::new(a, prime)?;
+    let b = Fp::new(b, prime)?;
+
+    // singular curves (4a³ + 27b² = 0) do not form a group
+    if !is_non_singular(a, b) {
+        return Err(format!(
+            "y² = x³ + {}x + {} is singular over 𝔽{}",
+            a.num, b.num, prime
+        ));
+    }
+
+    let mut points = vec![Point::infinity(a, b)];
+
+    for x in 0..prime as i64 {
+        for y in 0..prime as i64 {
+            let (x, y) = (Fp::new(x, prime)?, Fp::new(y, prime)?);
+
+            if let Ok(point) = Point::new(a, b, Some(x), Some(y)) {
+                points.push(point);
+            }
+        }
+    }
+
+    Ok(points)
+}
+
+/*
+ * The grid of P + Q for every pair of points, rows are P and columns are Q:
+ *
+ * +      | ∞      | (0, 1) | ...
+ * ∞      | ∞      | (0, 1) | ...
+ * (0, 1) | (0, 1) | (4, 2) | ...
+ */
+pub fn addition_table(prime: u32, a: i64, b: i64) -> Result<String, String> {
+    let points = curve_points(prime, a, b)?;
+    let labels: Vec<String> = points.iter().map(label).collect();
+    let width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(1);
+
+    let mut rows = vec![std::iter::once("+".to_string())
+        .chain(labels.iter().cloned())
+        .collect::<Vec<String>>()];
+
+    for (p, p_label) in points.iter().zip(labels.iter()) {
+        let mut row = vec![p_label.clone()];
+
+        for q in points.iter() {
+            row.push(label(&(*p + *q)?));
+        }
+
+        rows.push(row);
+    }
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<String>>()
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+fn label(point: &Point<Fp>) -> String {
+    match (point.x, point.y) {
+        (Some(x), Some(y)) => format!("({}, {})", x.num, y.num),
+        _ => "∞".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod addition_table_tests {
+    use super::*;
+
+    #[test]
+    fn test_curve_points() {
+        let points: Vec<String> = curve_points(5, 1, 1).unwrap().iter().map(label).collect();
+
+        assert_eq!(
+            points,
+            vec![
+                "∞", "(0, 1)", "(0, 4)", "(2, 1)", "(2, 4)", "(3, 1)", "(3, 4)", "(4, 2)", "(4, 3)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_curve_points_singular() {
+        assert_eq!(
+            curve_points(7, 0, 7).err(),
+            Some("y² = x³ + 0x + 0 is singular over 𝔽7".to_string())
+        );
+    }
+}
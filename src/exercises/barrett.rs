@@ -0,0 +1,310 @@
+// Barrett reduction: x mod p with a multiplication and a shift instead of a division
+//
+// For moduli that stay fixed across many operations (one curve, one field), the single
+// division is paid once when the field is set up
+
+use std::fmt;
+use std::ops;
+
+use crate::exercises::{ec_point::Field, error::CryptoError, finite_field::Fp};
+
+/*
+ * A prime p < 2³² with mu = ⌊2^k / p⌋, k = 2·bits(p)
+ *
+ * For x < p², q = ⌊x·mu / 2^k⌋ underestimates ⌊x / p⌋ by at most 2, so x - q·p lands in
+ * [0, 3p) and at most two subtractions finish the reduction
+ */
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct BarrettField {
+    prime: u64,
+    mu: u128,
+    shift: u32,
+}
+
+/*
+ * An element of a `BarrettField`, usable anywhere a `Field` is, e.g. as `Point<BarrettFp>`
+ */
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct BarrettFp {
+    pub num: u32,
+    pub field: BarrettField,
+}
+
+impl BarrettField {
+    // p = 1 is rejected along with 0: {0} is not a field, and inverting needs p - 2
+    pub fn new(prime: u32) -> Result<Self, CryptoError> {
+        if prime == 0 {
+            return Err(CryptoError::ZeroModulus);
+        }
+        if prime < 2 {
+            return Err(CryptoError::ModulusTooSmall(prime.into()));
+        }
+
+        let shift = 2 * (u32::BITS - prime.leading_zeros());
+
+        Ok(Self {
+            prime: prime as u64,
+            mu: (1_u128 << shift) / prime as u128,
+            shift,
+        })
+    }
+
+    pub fn prime(&self) -> u32 {
+        self.prime as u32
+    }
+
+    // x mod p, for any x < p² (e.g. the product of two reduced values)
+    pub fn reduce(&self, x: u64) -> u64 {
+        let quotient = ((x as u128 * self.mu) >> self.shift) as u64;
+        let mut remainder = x - quotient * self.prime;
+
+        while remainder >= self.prime {
+            remainder -= self.prime;
+        }
+
+        remainder
+    }
+
+    // a·b mod p for a, b < p
+    pub fn mul(&self, a: u32, b: u32) -> u32 {
+        self.reduce(a as u64 * b as u64) as u32
+    }
+
+    // a + b mod p for a, b < p, the sum is < 2p so one subtraction does
+    pub fn add(&self, a: u32, b: u32) -> u32 {
+        let sum = a as u64 + b as u64;
+
+        if sum >= self.prime {
+            (sum - self.prime) as u32
+        } else {
+            sum as u32
+        }
+    }
+
+    pub fn element(&self, num: u32) -> BarrettFp {
+        BarrettFp {
+            num: (num as u64 % self.prime) as u32,
+            field: *self,
+        }
+    }
+}
+
+impl BarrettFp {
+    pub fn from_fp(element: Fp) -> Result<Self, CryptoError> {
+        Ok(BarrettField::new(element.modulus)?.element(element.num))
+    }
+
+    pub fn to_fp(self) -> Fp {
+        Fp {
+            num: self.num,
+            modulus: self.field.prime(),
+        }
+    }
+
+    fn with_num(self, num: u32) -> Self {
+        Self {
+            num,
+            field: self.field,
+        }
+    }
+}
+
+impl Field for BarrettFp {
+    fn zero(&self) -> Self {
+        self.with_num(0)
+    }
+
+    fn one(&self) -> Self {
+        self.field.element(1)
+    }
+
+    // square and multiply, the exponent is used as is (not reduced)
+    fn pow(&self, exponent: u32) -> Self {
+        let mut result = self.one().num;
+        let mut base = self.num;
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.field.mul(result, base);
+            }
+            base = self.field.mul(base, base);
+            exponent >>= 1;
+        }
+
+        self.with_num(result)
+    }
+
+    // Fermat's little theorem: a⁻¹ = a^(p - 2)
+    fn mul_inverse(&self) -> Self {
+        self.pow(self.field.prime() - 2)
+    }
+
+    fn add_inverse(&self) -> Self {
+        self.with_num(self.field.add(self.field.prime() - self.num, 0))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    fn scalar_mul(&self, by: u32) -> Self {
+        *self * self.field.element(by)
+    }
+}
+
+impl fmt::Display for BarrettFp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} — 𝔽{}", self.num, self.field.prime())
+    }
+}
+
+impl ops::Add for BarrettFp {
+    type Output = Self;
+
+    // assumes `rhs` is in the same field
+    fn add(self, rhs: Self) -> Self::Output {
+        self.with_num(self.field.add(self.num, rhs.num))
+    }
+}
+
+impl ops::Sub for BarrettFp {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.num >= rhs.num {
+            self.with_num(self.num - rhs.num)
+        } else {
+            self.with_num((self.num as u64 + self.field.prime - rhs.num as u64) as u32)
+        }
+    }
+}
+
+impl ops::Mul for BarrettFp {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.with_num(self.field.mul(self.num, rhs.num))
+    }
+}
+
+impl ops::Div for BarrettFp {
+    type Output = Self;
+
+    // assumes `rhs` is not ZERO
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.mul_inverse()
+    }
+}
+
+impl ops::Neg for BarrettFp {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.add_inverse()
+    }
+}
+
+#[cfg(test)]
+mod barrett_tests {
+    use super::*;
+    use crate::exercises::ec_point::Point;
+
+    // xorshift64, enough to spread samples over the whole input range
+    fn samples(seed: u64, count: usize) -> impl Iterator<Item = u64> {
+        let mut state = seed;
+
+        (0..count).map(move |_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        })
+    }
+
+    #[test]
+    fn test_reduce_matches_remainder() {
+        for prime in [2_u32, 3, 7, 223, 65521, 2_147_483_647, 4_294_967_291] {
+            let field = BarrettField::new(prime).unwrap();
+            let square = prime as u64 * prime as u64;
+
+            for x in samples(prime as u64, 10_000).map(|x| x % square) {
+                assert_eq!(field.reduce(x), x % prime as u64);
+            }
+            assert_eq!(field.reduce(square - 1), (square - 1) % prime as u64);
+        }
+    }
+
+    #[test]
+    fn test_mul_add_match_fp() {
+        let prime = 65521;
+        let field = BarrettField::new(prime).unwrap();
+
+        for (a, b) in samples(1, 1000)
+            .zip(samples(2, 1000))
+            .map(|(a, b)| ((a % prime as u64) as u32, (b % prime as u64) as u32))
+        {
+            let expected = (a as u64 * b as u64 % prime as u64) as u32;
+
+            assert_eq!(field.mul(a, b), expected);
+            assert_eq!(
+                field.add(a, b),
+                ((a as u64 + b as u64) % prime as u64) as u32
+            );
+        }
+    }
+
+    #[test]
+    fn test_field_matches_fp() {
+        let prime = 223;
+
+        for num in 1..prime {
+            let fp = Fp::new(num as i64, prime).unwrap();
+            let barrett = BarrettFp::from_fp(fp).unwrap();
+
+            assert_eq!(barrett.pow(5).to_fp(), fp.pow(5));
+            assert_eq!(barrett.mul_inverse().to_fp(), fp.mul_inverse());
+            assert_eq!((-barrett).to_fp(), -fp);
+            assert_eq!(barrett.scalar_mul(9).to_fp(), fp.scalar_mul(9));
+        }
+
+        assert_eq!(BarrettField::new(0), Err(CryptoError::ZeroModulus));
+    }
+
+    #[test]
+    fn test_point_backing() {
+        // y² = x³ + 7 over 𝔽223, (47, 71) has order 21
+        let field = BarrettField::new(223).unwrap();
+        let point = Point::new(
+            field.element(0),
+            field.element(7),
+            Some(field.element(47)),
+            Some(field.element(71)),
+        )
+        .unwrap();
+
+        assert!(point.scalar_mul(21).is_infinity());
+        assert_eq!(
+            point.scalar_mul(5).x.map(BarrettFp::to_fp),
+            Point::new(
+                Fp::new(0, 223).unwrap(),
+                Fp::new(7, 223).unwrap(),
+                Some(Fp::new(47, 223).unwrap()),
+                Some(Fp::new(71, 223).unwrap())
+            )
+            .unwrap()
+            .scalar_mul(5)
+            .x
+        );
+    }
+
+    #[test]
+    fn test_invalid_modulus() {
+        assert_eq!(BarrettField::new(0), Err(CryptoError::ZeroModulus));
+        assert_eq!(BarrettField::new(1), Err(CryptoError::ModulusTooSmall(1)));
+
+        let one = BarrettField::new(2).unwrap().element(1);
+        assert_eq!(one.mul_inverse(), one);
+    }
+}
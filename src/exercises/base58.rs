@@ -0,0 +1,198 @@
+// Book: Programming Bitcoin: Learn How to Program Bitcoin from Scratch by Jimmy song
+// Chapter 4: Serialization — Base58
+
+use crate::exercises::hashes::hash256;
+
+/*
+ * The 58 alphanumeric characters minus the easily confused 0/O and l/I
+ */
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/*
+ * `bytes` read as one big-endian number, written in base 58. The number drops leading
+ * zero bytes so each of them is kept as a leading '1' (the zero digit)
+ *
+ * The conversion is schoolbook long division on the digits, O(n²) in the input length,
+ * which is fine for the 21-38 byte payloads of addresses and keys
+ */
+pub fn encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+
+    // base 58 digits, least significant first
+    let mut digits: Vec<u8> = Vec::new();
+
+    for &byte in &bytes[zeros..] {
+        let mut carry = byte as u32;
+
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    std::iter::repeat_n('1', zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|&digit| ALPHABET[digit as usize] as char),
+        )
+        .collect()
+}
+
+pub fn decode(s: &str) -> Result<Vec<u8>, String> {
+    let zeros = s.chars().take_while(|&c| c == '1').count();
+
+    // base 256 digits, least significant first
+    let mut bytes: Vec<u8> = Vec::new();
+
+    for c in s.chars().skip(zeros) {
+        let mut carry = match ALPHABET.iter().position(|&letter| letter as char == c) {
+            Some(value) => value as u32,
+            None => return Err(format!("'{}' is not a base58 character", c)),
+        };
+
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    Ok(std::iter::repeat_n(0, zeros)
+        .chain(bytes.into_iter().rev())
+        .collect())
+}
+
+/*
+ * Base58Check: the payload followed by the first 4 bytes of its double SHA-256, so a
+ * mistyped address or key is caught before it is used
+ */
+pub fn encode_check(payload: &[u8]) -> String {
+    let mut bytes = payload.to_vec();
+    bytes.extend_from_slice(&checksum(payload));
+
+    encode(&bytes)
+}
+
+pub fn decode_check(s: &str) -> Result<Vec<u8>, String> {
+    let bytes = decode(s)?;
+
+    if bytes.len() < 4 {
+        return Err("base58check string is shorter than its checksum".to_string());
+    }
+
+    let (payload, expected) = bytes.split_at(bytes.len() - 4);
+    if checksum(payload) != expected {
+        return Err("invalid base58check checksum".to_string());
+    }
+
+    Ok(payload.to_vec())
+}
+
+fn checksum(payload: &[u8]) -> [u8; 4] {
+    let hash = hash256(payload);
+
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+#[cfg(test)]
+mod base58_tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_encode() {
+        // Programming Bitcoin, chapter 4 exercise 4
+        let vectors = [
+            (
+                "7c076ff316692a3d7eb3c3bb0f8b1488cf72e1afcd929e29307032997a838a3d",
+                "9MA8fRQrT4u8Zj8ZRd6MAiiyaxb2Y1CMpvVkHQu5hVM6",
+            ),
+            (
+                "eff69ef2b1bd93a66ed5219add4fb51e11a840f404876325a1e8ffe0529a2c",
+                "4fE3H2E6XMp4SsxtwinF7w9a34ooUrwWe4WsW1458Pd",
+            ),
+            (
+                "c7207fee197d27c618aea621406f6bf5ef6fca38681d82b2f06fddbdce6feab6",
+                "EQJsjkd6JaGwxrjEhfeqPenqHwrBmPQZjJGNSCHBkcF7",
+            ),
+            ("000001", "112"),
+            ("00", "1"),
+            ("", ""),
+        ];
+
+        for (hex, expected) in vectors {
+            assert_eq!(encode(&from_hex(hex)), expected);
+            assert_eq!(decode(expected), Ok(from_hex(hex)));
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let inputs: [&[u8]; 4] = [
+            &[0, 0, 0, 255, 0],
+            &[255; 32],
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+            &[0; 5],
+        ];
+
+        for input in inputs {
+            assert_eq!(decode(&encode(input)), Ok(input.to_vec()));
+        }
+    }
+
+    #[test]
+    fn test_decode_invalid_character() {
+        assert_eq!(
+            decode("9MA80"),
+            Err("'0' is not a base58 character".to_string())
+        );
+        assert_eq!(
+            decode("l1"),
+            Err("'l' is not a base58 character".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_known_address() {
+        let address = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+        let payload = from_hex("0077bff20c60e522dfaa3350c39b030a5d004e839a");
+
+        assert_eq!(decode_check(address), Ok(payload.clone()));
+        assert_eq!(encode_check(&payload), address);
+    }
+
+    #[test]
+    fn test_check_corrupted() {
+        assert_eq!(
+            decode_check("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"),
+            Err("invalid base58check checksum".to_string())
+        );
+        assert_eq!(
+            decode_check("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNV"),
+            Err("invalid base58check checksum".to_string())
+        );
+        assert_eq!(
+            decode_check("111"),
+            Err("base58check string is shorter than its checksum".to_string())
+        );
+    }
+}
@@ -0,0 +1,396 @@
+// BIP-173 / BIP-350: Bech32 and Bech32m
+//
+// A human-readable part, a '1' separator and the data in 5-bit groups written with a
+// 32-character alphabet, followed by a 6-character BCH checksum over both
+
+/*
+ * No '1', 'b', 'i' or 'o', ordered so that likely typos change few bits
+ */
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+// longest string BIP-173 allows, checksum included
+const MAX_LENGTH: usize = 90;
+
+/*
+ * The constant the checksum is made to hit: Bech32 (BIP-173) uses 1, Bech32m (BIP-350)
+ * changes it to fix Bech32's weakness to inserted or deleted 'q's before a final 'p'
+ */
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Bech32Variant {
+    Bech32,
+    Bech32m,
+}
+
+impl Bech32Variant {
+    fn constant(self) -> u32 {
+        match self {
+            Bech32Variant::Bech32 => 1,
+            Bech32Variant::Bech32m => 0x2bc830a3,
+        }
+    }
+}
+
+/*
+ * The remainder of the values, read as coefficients of a polynomial over 𝔾𝔽(32), modulo
+ * the BCH generator
+ */
+fn polymod(values: impl IntoIterator<Item = u8>) -> u32 {
+    values.into_iter().fold(1, |checksum, value| {
+        let top = checksum >> 25;
+        let checksum = ((checksum & 0x1ffffff) << 5) ^ value as u32;
+
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(checksum, |checksum, (_, g)| checksum ^ g)
+    })
+}
+
+// the high bits of each character, a zero, then the low bits of each character
+fn expand_hrp(hrp: &str) -> Vec<u8> {
+    hrp.bytes()
+        .map(|c| c >> 5)
+        .chain(std::iter::once(0))
+        .chain(hrp.bytes().map(|c| c & 31))
+        .collect()
+}
+
+/*
+ * Regroups `data` from `from`-bit to `to`-bit values, big-endian. With `pad` the last
+ * group is filled up with zeros, without it the leftover bits have to be fewer than
+ * `from` and all zero (i.e. the padding an encoder would have added)
+ */
+pub fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, String> {
+    let mut accumulator: u32 = 0;
+    let mut bits = 0;
+    let mut groups = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+    let mask = (1 << to) - 1;
+
+    for &value in data {
+        if value as u32 >> from != 0 {
+            return Err(format!("{} does not fit in {} bits", value, from));
+        }
+
+        accumulator = (accumulator << from) | value as u32;
+        bits += from;
+
+        while bits >= to {
+            bits -= to;
+            groups.push(((accumulator >> bits) & mask) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            groups.push(((accumulator << (to - bits)) & mask) as u8);
+        }
+    } else if bits >= from || (accumulator << (to - bits)) & mask != 0 {
+        return Err("invalid padding in the data part".to_string());
+    }
+
+    Ok(groups)
+}
+
+// `groups` are already 5-bit values
+fn encode_groups(hrp: &str, groups: &[u8], variant: Bech32Variant) -> Result<String, String> {
+    if hrp.is_empty() || hrp.len() > MAX_LENGTH - 7 {
+        return Err(format!(
+            "human-readable part must be 1 to 83 characters, got {}",
+            hrp.len()
+        ));
+    }
+    if let Some(c) = hrp.chars().find(|&c| !('!'..='~').contains(&c)) {
+        return Err(format!("{:?} is not allowed in the human-readable part", c));
+    }
+    if hrp.len() + 1 + groups.len() + 6 > MAX_LENGTH {
+        return Err(format!(
+            "bech32 strings are at most {} characters",
+            MAX_LENGTH
+        ));
+    }
+
+    let hrp = hrp.to_lowercase();
+    let mut values = expand_hrp(&hrp);
+    values.extend_from_slice(groups);
+    values.extend_from_slice(&[0; 6]);
+    let checksum = polymod(values) ^ variant.constant();
+
+    let checksum_groups = (0..6).map(|i| ((checksum >> (5 * (5 - i))) & 31) as u8);
+
+    Ok(hrp
+        + "1"
+        + &groups
+            .iter()
+            .copied()
+            .chain(checksum_groups)
+            .map(|group| CHARSET[group as usize] as char)
+            .collect::<String>())
+}
+
+// the human-readable part (lowercase), the 5-bit groups without the checksum, the variant
+fn decode_groups(s: &str) -> Result<(String, Vec<u8>, Bech32Variant), String> {
+    if s.len() > MAX_LENGTH {
+        return Err(format!(
+            "bech32 strings are at most {} characters",
+            MAX_LENGTH
+        ));
+    }
+    if let Some(c) = s.chars().find(|&c| !('!'..='~').contains(&c)) {
+        return Err(format!("{:?} is not allowed in a bech32 string", c));
+    }
+    if s.chars().any(|c| c.is_ascii_lowercase()) && s.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err("bech32 strings cannot mix upper and lower case".to_string());
+    }
+
+    let s = s.to_lowercase();
+    let (hrp, data) = match s.rfind('1') {
+        Some(separator) => (&s[..separator], &s[separator + 1..]),
+        None => return Err("no '1' separator".to_string()),
+    };
+
+    if hrp.is_empty() {
+        return Err("human-readable part is empty".to_string());
+    }
+    if data.len() < 6 {
+        return Err(format!("checksum needs 6 characters, got {}", data.len()));
+    }
+
+    let groups = data
+        .chars()
+        .map(
+            |c| match CHARSET.iter().position(|&letter| letter as char == c) {
+                Some(group) => Ok(group as u8),
+                None => Err(format!("'{}' is not a bech32 character", c)),
+            },
+        )
+        .collect::<Result<Vec<u8>, String>>()?;
+
+    let mut values = expand_hrp(hrp);
+    values.extend_from_slice(&groups);
+
+    let variant = match polymod(values) {
+        1 => Bech32Variant::Bech32,
+        0x2bc830a3 => Bech32Variant::Bech32m,
+        _ => return Err("invalid bech32 checksum".to_string()),
+    };
+
+    Ok((
+        hrp.to_string(),
+        groups[..groups.len() - 6].to_vec(),
+        variant,
+    ))
+}
+
+/*
+ * `data` is regrouped from bytes into 5-bit values (zero padded) before being written out
+ */
+pub fn encode(hrp: &str, data: &[u8], variant: Bech32Variant) -> Result<String, String> {
+    encode_groups(hrp, &convert_bits(data, 8, 5, true)?, variant)
+}
+
+/*
+ * The human-readable part (lowercase), the data regrouped back into bytes and which of the
+ * two checksums matched. Either all-lowercase or all-uppercase strings are accepted
+ */
+pub fn decode(s: &str) -> Result<(String, Vec<u8>, Bech32Variant), String> {
+    let (hrp, groups, variant) = decode_groups(s)?;
+
+    Ok((hrp, convert_bits(&groups, 5, 8, false)?, variant))
+}
+
+/*
+ * A SegWit address (BIP-173 / BIP-350): the witness version as a single 5-bit group,
+ * then the witness program. Version 0 keeps the original Bech32 checksum, versions 1 to
+ * 16 use Bech32m
+ */
+pub fn encode_segwit(hrp: &str, version: u8, program: &[u8]) -> Result<String, String> {
+    if version > 16 {
+        return Err(format!("witness version must be 0 to 16, got {}", version));
+    }
+    if !(2..=40).contains(&program.len()) {
+        return Err(format!(
+            "witness program must be 2 to 40 bytes, got {}",
+            program.len()
+        ));
+    }
+    if version == 0 && program.len() != 20 && program.len() != 32 {
+        return Err(format!(
+            "version 0 witness program must be 20 or 32 bytes, got {}",
+            program.len()
+        ));
+    }
+
+    let variant = if version == 0 {
+        Bech32Variant::Bech32
+    } else {
+        Bech32Variant::Bech32m
+    };
+    let mut groups = vec![version];
+    groups.extend(convert_bits(program, 8, 5, true)?);
+
+    encode_groups(hrp, &groups, variant)
+}
+
+#[cfg(test)]
+mod bech32_tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_checksums() {
+        // BIP-173 and BIP-350 valid test vectors
+        let bech32 = [
+            "A12UEL5L",
+            "a12uel5l",
+            "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+            "11qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqc8247j",
+            "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w",
+            "?1ezyfcl",
+        ];
+        let bech32m = [
+            "A1LQFN3A",
+            "a1lqfn3a",
+            "an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6",
+            "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+            "11llllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllludsr8",
+            "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+            "?1v759aa",
+        ];
+
+        for (strings, variant) in [
+            (bech32, Bech32Variant::Bech32),
+            (bech32m, Bech32Variant::Bech32m),
+        ] {
+            for s in strings {
+                let (hrp, groups, decoded_variant) = decode_groups(s).unwrap();
+
+                assert_eq!(decoded_variant, variant);
+                assert_eq!(encode_groups(&hrp, &groups, variant), Ok(s.to_lowercase()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_strings() {
+        // BIP-173 and BIP-350 invalid test vectors, none of which may decode
+        let invalid = [
+            "\u{20}1nwldj5",
+            "\u{7f}1axkwrx",
+            "\u{80}1eym55h",
+            "an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1569pvx",
+            "pzry9x0s0muk",
+            "1pzry9x0s0muk",
+            "x1b4n0q5v",
+            "li1dgmt3",
+            "de1lg7wt\u{ff}",
+            "10a06t8",
+            "1qzzfhee",
+            "\u{20}1xj0phk",
+            "\u{7f}1g6xzxy",
+            "\u{80}1vctc34",
+            "an84characterslonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11d6pts4",
+            "qyrz8wqd2c9m",
+            "1qyrz8wqd2c9m",
+            "y1b0jsk6g",
+            "lt1igcx5c0",
+            "in1muywd",
+            "mm1crxm3i",
+            "au1s5cgom",
+            "16plkw9",
+            "1p2gdwpf",
+        ];
+
+        for s in invalid {
+            assert!(decode_groups(s).is_err(), "{:?} decoded", s);
+            assert!(decode(s).is_err(), "{:?} decoded", s);
+        }
+    }
+
+    #[test]
+    fn test_invalid_checksums() {
+        // checksums computed over the uppercase human-readable part
+        for s in ["A1G7SGD8", "M1VUXWEZ"] {
+            assert_eq!(decode(s), Err("invalid bech32 checksum".to_string()));
+        }
+
+        // any single substituted character is caught
+        let valid = "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw";
+        for i in 7..valid.len() {
+            let mut mutated = valid.as_bytes().to_vec();
+            mutated[i] = if mutated[i] == b'q' { b'p' } else { b'q' };
+
+            assert_eq!(
+                decode(std::str::from_utf8(&mutated).unwrap()),
+                Err("invalid bech32 checksum".to_string())
+            );
+        }
+
+        // a Bech32 string is not a Bech32m string with a different checksum
+        assert_eq!(decode("a12uel5l").unwrap().2, Bech32Variant::Bech32);
+        assert_eq!(decode("a1lqfn3a").unwrap().2, Bech32Variant::Bech32m);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let data: Vec<u8> = (0..40).collect();
+
+        for variant in [Bech32Variant::Bech32, Bech32Variant::Bech32m] {
+            for length in [0, 1, 5, 20, 32, 40] {
+                let encoded = encode("bc", &data[..length], variant).unwrap();
+
+                assert_eq!(
+                    decode(&encoded),
+                    Ok(("bc".to_string(), data[..length].to_vec(), variant))
+                );
+                assert_eq!(decode(&encoded.to_uppercase()), decode(&encoded));
+            }
+        }
+
+        // 20 bytes are exactly 32 groups, "abcdef1qpzry..." spells out the whole charset
+        let (_, bytes, _) = decode("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw").unwrap();
+        assert_eq!(
+            encode("abcdef", &bytes, Bech32Variant::Bech32),
+            Ok("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw".to_string())
+        );
+    }
+
+    #[test]
+    fn test_encode_segwit() {
+        // BIP-173 / BIP-350 valid addresses
+        let program = hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        assert_eq!(
+            encode_segwit("bc", 0, &program),
+            Ok("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4".to_string())
+        );
+
+        let program = hex::decode("751e").unwrap();
+        assert_eq!(
+            encode_segwit("bc", 16, &program),
+            Ok("bc1sw50qgdz25j".to_string())
+        );
+
+        assert!(encode_segwit("bc", 17, &[0; 20]).is_err());
+        assert!(encode_segwit("bc", 0, &[0; 21]).is_err());
+        assert!(encode_segwit("bc", 1, &[0; 41]).is_err());
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        assert!(encode("", &[], Bech32Variant::Bech32).is_err());
+        assert!(encode("a b", &[], Bech32Variant::Bech32).is_err());
+        assert!(encode("bc", &[0; 60], Bech32Variant::Bech32).is_err());
+
+        // 2 leftover bits that are not zero cannot come from bytes
+        assert_eq!(
+            decode("11llllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllludsr8"),
+            Err("invalid padding in the data part".to_string())
+        );
+        assert_eq!(
+            convert_bits(&[32], 5, 8, false),
+            Err("32 does not fit in 5 bits".to_string())
+        );
+    }
+}
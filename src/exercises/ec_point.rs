@@ -6,10 +6,13 @@
 // )__)  )(__  )(__  _)(_  )___/  )(   _)(_ ( (__      ( (__  )(__)(  )   / \  /  )__) \__ \
 // (____)(____)(____)(____)(__)   (__) (____) \___)      \___)(______)(_)\_)  \/  (____)(___/
 
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::fmt::{self, Display};
 use std::ops;
 
+use crate::exercises::{error::CryptoError, finite_field::Fp, hashes::tagged_hash};
+
 /* The general form of the curve is y² = x³ + ax + b, but more specifically the
  * `secp256k1` curve used by bitcoin and ethereum has the equation y² = x³ + 7
  * where `a` equals 0 and `b` equals 7
@@ -25,9 +28,71 @@ pub struct Point<F> {
     pub y: Option<F>,
 }
 
+/*
+ * A named curve y² = x³ + ax + b, used to label points when several curves are in play
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveParams<F> {
+    pub name: &'static str,
+    pub a: F,
+    pub b: F,
+}
+
+/*
+ * A fixed base point with its multiples 0·base, 1·base, ..., (2^window - 1)·base
+ * precomputed, for when the same point (usually the generator) is multiplied by many scalars
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrecomputedPoint<F> {
+    window: u8,
+    table: Vec<Point<F>>,
+}
+
+/*
+ * A point with the multiples asked of it remembered, see `Point::mul_cache`. Filled in
+ * only as `multiply` is called: `doublings` holds base, 2·base, 4·base, ... up to the
+ * highest bit seen so far and `products` every scalar already answered
+ */
+#[derive(Debug, Clone)]
+pub struct MulCache<F> {
+    base: Point<F>,
+    doublings: Vec<Point<F>>,
+    products: BTreeMap<u32, Point<F>>,
+}
+
+/*
+ * P, 2P, 3P, ..., P(∞): the cyclic subgroup generated by a point, in order, see
+ * `Point::subgroup`
+ */
+#[derive(Debug, Clone)]
+pub struct SubgroupIter<F> {
+    base: Point<F>,
+    next: Option<Point<F>>,
+}
+
+/*
+ * Jacobian coordinates: (X : Y : Z) is the affine point (X/Z², Y/Z³), and Z = 0 is P(∞)
+ *
+ * Adding and doubling only multiply, the single inversion is left for `to_affine`, so a
+ * scalar multiplication does one inversion in total rather than one per group operation
+ */
+#[derive(Debug, Clone)]
+struct Jacobian<F> {
+    x: F,
+    y: F,
+    z: F,
+}
+
+/*
+ * Arithmetic the curve code needs from the underlying field
+ *
+ * Only `Clone` is required so the same API works for big-integer backed fields, which
+ * cannot be `Copy`: methods borrow `self` and return owned values, and the field of an
+ * element (its modulus) is carried by the element itself, hence `zero` and `one` are
+ * taken from an existing element rather than from a `u32` modulus
+ */
 pub trait Field:
-    Copy
-    + Clone
+    Clone
     + Debug
     + Display
     + PartialEq
@@ -37,32 +102,165 @@ pub trait Field:
     + std::ops::Div<Output = Self>
     + std::ops::Neg<Output = Self>
 {
-    fn zero(modulus: u32) -> Self;
-    fn one(modulus: u32) -> Self;
-    fn modulus(&self) -> u32;
-    fn mul_inverse(self) -> Self;
-    fn pow(self, exp: u32) -> Self;
-    fn add_inverse(self) -> Self;
-    fn is_zero(self) -> bool;
-    fn scalar_mul(self, by: u32) -> Self;
+    // the additive identity of the field `self` belongs to
+    fn zero(&self) -> Self;
+    // the multiplicative identity of the field `self` belongs to
+    fn one(&self) -> Self;
+    fn mul_inverse(&self) -> Self;
+    fn pow(&self, exp: u32) -> Self;
+    fn add_inverse(&self) -> Self;
+    fn is_zero(&self) -> bool;
+    fn scalar_mul(&self, by: u32) -> Self;
+
+    /*
+     * What `Default::default` would be if the field were known without an element: ZERO
+     * of `self`'s field
+     */
+    fn default_element(&self) -> Self {
+        self.zero()
+    }
+}
+
+/*
+ * A field of prime order, whose elements are integers in [0, p): square roots (when they
+ * exist) can be taken, which is what recovering a point from its x-coordinate needs, and
+ * elements have a canonical big-endian byte encoding, which is what SEC needs
+ */
+pub trait PrimeField: Field {
+    // some `r` with r² = self, an error when `self` is not a quadratic residue
+    fn sqrt(&self) -> Result<Self, CryptoError>;
+    // big-endian, always as many bytes as it takes to write p - 1
+    fn to_be_bytes(&self) -> Vec<u8>;
+    // the element of `self`'s field with the big-endian value `bytes`, which must be < p
+    fn with_be_bytes(&self, bytes: &[u8]) -> Result<Self, CryptoError>;
+    fn is_odd(&self) -> bool;
+}
+
+/*
+ * A named curve y² = x³ + ax + b with a fixed base point, implemented by a (usually empty)
+ * marker type per curve, e.g. `Secp256k1`
+ *
+ * `Point` itself still carries `a` and `b` as values, so the toy curves over `Fp` can be
+ * picked at runtime; the trait is what makes `a` and `b` disappear from the call sites of
+ * curves known up front. Points of two different curves still fail to add at runtime with
+ * `CryptoError::DifferentCurves`
+ */
+#[cfg(feature = "bigint")]
+pub trait WeierstrassCurve {
+    type Field: PrimeField;
+
+    fn a() -> Self::Field;
+    fn b() -> Self::Field;
+    fn field_prime() -> num_bigint::BigUint;
+    fn generator() -> Point<Self::Field>;
+    // the order n of the group generated by the generator, n·G = P(∞)
+    fn order() -> num_bigint::BigUint;
+
+    fn point(x: Self::Field, y: Self::Field) -> Result<Point<Self::Field>, CryptoError> {
+        Point::new(Self::a(), Self::b(), Some(x), Some(y))
+    }
+
+    fn infinity() -> Point<Self::Field> {
+        Point::infinity(Self::a(), Self::b())
+    }
+
+    // whether `point` was built over this curve (says nothing about the subgroup)
+    fn contains(point: &Point<Self::Field>) -> bool {
+        point.a == Self::a() && point.b == Self::b()
+    }
+}
+
+/*
+ * The `b` that puts (x, y) on y² = x³ + ax + b, i.e. b = y² - x³ - ax. Any point picks
+ * out exactly one curve for a given `a`
+ */
+pub fn curve_b_for<F: Field>(a: F, x: F, y: F) -> F {
+    y.pow(2) - x.pow(3) - a * x
+}
+
+/*
+ * Whether y² = x³ + ax + b is an elliptic curve at all: its discriminant 4a³ + 27b² is not
+ * zero. A singular curve has a cusp or a node, and its points either do not form a group
+ * or form one where discrete logs are easy
+ */
+pub fn is_non_singular<F: Field>(a: F, b: F) -> bool {
+    !(a.pow(3).scalar_mul(4) + b.pow(2).scalar_mul(27)).is_zero()
+}
+
+/*
+ * A point on y² = x³ + ax + b determined by `msg`, by try-and-increment: x is read from
+ * the first bytes of tagged_hash("hash_to_curve", msg || counter) and the counter is
+ * bumped until x is below p and x³ + ax + b is a square; of the two roots the even y is
+ * kept. About half of all x work, so a couple of tries are usually enough
+ *
+ * NOT constant-time: the number of tries depends on `msg`, so it must not be used on
+ * secret inputs. Nobody knows the discrete log of the result, which is what makes it
+ * usable as an independent generator
+ */
+pub fn hash_to_curve<F: PrimeField>(msg: &[u8], a: F, b: F) -> Point<F> {
+    let width = a.to_be_bytes().len().min(32);
+
+    for counter in 0_u32.. {
+        let mut input = msg.to_vec();
+        input.extend_from_slice(&counter.to_be_bytes());
+        let hash = tagged_hash("hash_to_curve", &input);
+
+        let Ok(x) = a.with_be_bytes(&hash[..width]) else {
+            continue;
+        };
+        if let Ok((point, other)) = Point::from_x(x, a.clone(), b.clone()) {
+            return match point.y_is_even() {
+                Some(false) => other,
+                _ => point,
+            };
+        }
+    }
+
+    unreachable!("a non-singular curve has points with an x below 2^32 tries")
+}
+
+/*
+ * Pedersen commitment value·G + blinding·H: hiding (with a random blinding every value is
+ * equally likely) and binding (opening it two ways gives away log_G(H)) as long as nobody
+ * knows log_G(H), e.g. H from `hash_to_curve`
+ *
+ * Commitments add: C(v₁, b₁) + C(v₂, b₂) = C(v₁ + v₂, b₁ + b₂)
+ */
+pub fn pedersen_commit<F: Field>(
+    g: Point<F>,
+    h: Point<F>,
+    value: u32,
+    blinding: u32,
+) -> Result<Point<F>, CryptoError> {
+    Point::mul_add(g, value, h, blinding)
 }
 
 impl<F: Field> Point<F> {
-    pub fn new(_a: F, _b: F, _x: Option<F>, _y: Option<F>) -> Result<Self, String> {
+    pub fn new(_a: F, _b: F, _x: Option<F>, _y: Option<F>) -> Result<Self, CryptoError> {
+        // everything has to be in `a`'s field before the shape or the equation mean
+        // anything; ZERO carries the field, so comparing ZEROs compares fields
+        let field = _a.zero();
+        if [Some(&_b), _x.as_ref(), _y.as_ref()]
+            .into_iter()
+            .flatten()
+            .any(|element| element.zero() != field)
+        {
+            return Err(CryptoError::DifferentFields {
+                operation: "define a point with",
+            });
+        }
+
         match _x {
             Some(x_value) => match _y {
                 Some(y_value) => {
-                    let lhs = y_value.pow(2);
-                    let rhs_0 = x_value.pow(3);
-                    let rhs_1 = _a * x_value;
-                    let rhs_01 = rhs_0 + rhs_1;
-                    let rhs = rhs_01 + _b;
-
+                    let (lhs, rhs) = Self::curve_sides(&x_value, &y_value, &_a, &_b);
                     if lhs != rhs {
-                        return Err(format!(
-                            "coordinates ({}, {}) is not on the curve",
-                            x_value, y_value
-                        ));
+                        return Err(CryptoError::NotOnCurve {
+                            x: x_value.to_string(),
+                            y: y_value.to_string(),
+                            lhs: lhs.to_string(),
+                            rhs: rhs.to_string(),
+                        });
                     }
 
                     Ok(Self {
@@ -72,364 +270,2917 @@ impl<F: Field> Point<F> {
                         y: Some(y_value),
                     })
                 }
-                None => return Err(format!("Invalid infinity point")),
+                None => Err(CryptoError::InvalidInfinity),
             },
             None => match _y {
-                Some(_) => return Err(format!("Invalid infinity point")),
-                None => {
-                    return Ok(Self {
-                        a: _a,
-                        b: _b,
-                        x: None,
-                        y: None,
-                    })
-                }
+                Some(_) => Err(CryptoError::InvalidInfinity),
+                None => Ok(Self::infinity(_a, _b)),
             },
         }
     }
 
-    #[allow(dead_code)]
-    pub fn scalar_mul(self, by: u32) -> Self {
-        let mut product = self;
+    // `new`, but refusing points (P(∞) included) of a singular curve
+    pub fn new_checked(a: F, b: F, x: Option<F>, y: Option<F>) -> Result<Self, CryptoError> {
+        if !is_non_singular(a.clone(), b.clone()) {
+            return Err(CryptoError::SingularCurve {
+                a: a.to_string(),
+                b: b.to_string(),
+            });
+        }
 
-        if by == 0 {
-            return Point::new(self.a, self.b, None, None).unwrap();
-        } else if by > 1 {
-            // TODO: make this more efficient using "binary expansion"
-            for _ in 0..(by - 1) {
-                product = (product + self).unwrap();
-            }
+        Self::new(a, b, x, y)
+    }
+
+    // the point at infinity P(∞) on y² = x³ + ax + b, the identity of point addition
+    pub fn infinity(a: F, b: F) -> Self {
+        Self {
+            a,
+            b,
+            x: None,
+            y: None,
         }
+    }
 
-        product
+    /*
+     * P + P, straight from the tangent line at P (Case 2 of `Add`) without going through
+     * the distinct/equal point checks:
+     *
+     * s (slope) = (3x² + a)/2y
+     * x₃ = s² - 2x
+     * y₃ = s(x - x₃) - y
+     *
+     * The tangent is vertical when `y` = 0, so 2P = P(∞); 2P(∞) = P(∞)
+     */
+    pub fn double(&self) -> Result<Self, CryptoError> {
+        let (x, y) = match (&self.x, &self.y) {
+            (Some(x), Some(y)) if !y.is_zero() => (x, y),
+            _ => return Ok(Point::infinity(self.a.clone(), self.b.clone())),
+        };
+
+        let slope = (x.pow(2).scalar_mul(3) + self.a.clone()) / y.scalar_mul(2);
+        let x3 = slope.pow(2) - x.scalar_mul(2);
+        let y3 = (slope * (x.clone() - x3.clone())) - y.clone();
+
+        Point::new(self.a.clone(), self.b.clone(), Some(x3), Some(y3))
     }
 
+    /*
+     * Double-and-add (binary expansion) of `by`: walking the bits from the most
+     * significant one down, the running result is doubled at every bit and the
+     * point is added whenever the bit is set, i.e. O(log n) group operations
+     *
+     * e.g. 13 = 0b1101 => ((((P)·2 + P)·2)·2 + P)
+     *
+     * Edge cases (never panics on them, signature code may hand us a zero scalar):
+     * - 0·P = P(∞), there are no bits to walk so the identity is returned as is
+     * - 1·P = P
+     * - k·P(∞) = P(∞) for every `k`
+     */
     #[allow(dead_code)]
-    pub fn is_infinity(self) -> bool {
-        self.x.is_none() && self.y.is_none()
-    }
-}
+    pub fn scalar_mul(&self, by: u32) -> Self {
+        let base = Jacobian::from_affine(self);
+        let mut product = Jacobian::infinity(&self.a);
 
-impl<F: Field> ops::Add for Point<F> {
-    type Output = Result<Self, String>;
+        for bit in (0..(u32::BITS - by.leading_zeros())).rev() {
+            product = product.double(&self.a);
 
-    fn add(self, point_2: Self) -> Self::Output {
-        if self.a != point_2.a || self.b != point_2.b {
-            return Err(format!("Point addition invalid on different curves"));
+            if (by >> bit) & 1 == 1 {
+                product = product.add(&base, &self.a);
+            }
         }
 
-        let slope: F;
+        product.to_affine(self.a.clone(), self.b.clone())
+    }
+
+    /*
+     * The running result of `scalar_mul` after each of its steps, for watching the
+     * algorithm work: one doubling per bit of `by` from the most significant down (the
+     * first one doubles P(∞)) and one addition per set bit, so bits + ones entries, the
+     * last of which is by·P. Empty for 0. Done in affine coordinates, so it is slower
+     */
+    pub fn scalar_mul_steps(self, by: u32) -> Vec<Self> {
+        let mut product = Point::infinity(self.a.clone(), self.b.clone());
+        let mut steps = vec![];
 
-        /*
-         * Case 1(a): first point is at infinity P₁ = P(∞)
-         */
-        if self.x.is_none() && self.y.is_none() {
-            if point_2.x.is_none() && point_2.y.is_none() {
-                return Ok(Point::new(self.a, self.b, None, None).unwrap());
-            }
+        for bit in (0..(u32::BITS - by.leading_zeros())).rev() {
+            product = product.double().unwrap();
+            steps.push(product.clone());
 
-            return Ok(Point::new(
-                self.a,
-                self.b,
-                point_2.x,
-                point_2.y.map(|y| y.add_inverse()),
-            )
-            .unwrap());
-        }
-        /*
-         * Case 1(b): second point is at infinity P₂ = P(∞)
-         */
-        else if point_2.x.is_none() && point_2.y.is_none() {
-            if self.x.is_none() && self.y.is_none() {
-                return Ok(Point::new(self.a, self.b, None, None).unwrap());
+            if (by >> bit) & 1 == 1 {
+                product = (product + self.clone()).unwrap();
+                steps.push(product.clone());
             }
-
-            // if the other point is on the curve, we flip the `y` value
-            return Ok(
-                Point::new(self.a, self.b, self.x, self.y.map(|y| y.add_inverse())).unwrap(),
-            );
         }
 
-        let x1_value = self.x.unwrap();
-        let y1_value = self.y.unwrap();
-        let x2_value = point_2.x.unwrap();
-        let y2_value = point_2.y.unwrap();
+        steps
+    }
 
-        /*
-         * Case 2: same points where P₁ == P₂
-         * Same formula as Case 3, except:
-         *
-         * s (slope) = (3x² + a)/2y;  | dy/dx => y² = x³ + ax + b
-         */
-        if self.x == point_2.x && self.y == point_2.y {
-            /*
-             * Case 2 (variant): same points where P₁ == P₂ and `y` = 0; `s` denominator results in zero
-             * meaning slope is `undefined`. This results in P(∞)
-             */
-            if self.y.unwrap().is_zero() {
-                return Ok(Point::new(self.a, self.b, None, None).unwrap());
-            }
+    /*
+     * `scalar_mul` for a scalar given as bits, little-endian: bits[0] is the least
+     * significant bit. Leading (high) `false`s cost a doubling of P(∞) each and don't change
+     * the result, and an empty slice is 0
+     */
+    pub fn scalar_mul_bits(&self, bits: &[bool]) -> Self {
+        let base = Jacobian::from_affine(self);
+        let mut product = Jacobian::infinity(&self.a);
 
-            slope = (x1_value.pow(2).scalar_mul(3) + self.a) / y1_value.scalar_mul(2);
-        }
-        /*
-         * Case 3 (base case): distinct points where P₁ != P₂
-         * P₁ = (x₁, y₁);  P₂ = (x₂, y₂);  P₃ = (x₃, y₃)
-         * P₁ + P₂ = P₃
-         *
-         * s (slope) = (y₂ - y₁) / (x₂ - x₁)
-         * x₃ = s² - x₁ - x₂
-         * y₃ = s(x₁ - x₃) - y₁
-         */
-        else {
-            /*
-             * Case 3 (variant) - if the two `x` points are equivalent and `y` points are negated, i.e point_a.x == point_b.x && point_a.y == -(point_b.y)
-             * This results in the infinity point
-             */
-            if self.x == point_2.x && (y1_value + y2_value).is_zero() {
-                return Ok(Point::new(self.a, self.b, None, None).unwrap());
-            }
+        for &bit in bits.iter().rev() {
+            product = product.double(&self.a);
 
-            slope = (y2_value - y1_value) / (x2_value - x1_value);
+            if bit {
+                product = product.add(&base, &self.a);
+            }
         }
 
-        let point_3_x = (slope.pow(2) - x1_value) - x2_value;
-        let point_3_y = (slope * (x1_value - point_3_x)) - y1_value;
-
-        Ok(Point::new(self.a, self.b, Some(point_3_x), Some(point_3_y)).unwrap())
+        product.to_affine(self.a.clone(), self.b.clone())
     }
-}
 
-impl<F: Field> fmt::Display for Point<F> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.x.is_none() && self.y.is_none() {
-            return write!(f, "• (∞, ∞)");
+    /*
+     * The Q with 2Q = P, given the (odd) order n of P: 2 is invertible mod n with
+     * 2⁻¹ = (n + 1)/2, and Q = 2⁻¹·P. Q is then the only half of P inside ⟨P⟩, P(∞)
+     * included (its half is itself)
+     *
+     * With n even, 2 has no inverse: halves may not exist, or there are several
+     */
+    pub fn half(&self, order: u32) -> Result<Self, CryptoError> {
+        if order.is_multiple_of(2) {
+            return Err(CryptoError::EvenOrder(order));
         }
 
-        write!(f, "• ({}, {})", self.x.unwrap(), self.y.unwrap(),)
+        Ok(self.scalar_mul(order / 2 + 1))
     }
-}
 
-#[cfg(test)]
-mod ecc_tests {
-    use crate::exercises::finite_field::Fp;
+    /*
+     * `scalar_mul` over the width-w NAF of `scalar` (see `wnaf`): the odd multiples
+     * P, 3P, ..., (2^(w-1) - 1)·P are computed once, then each digit costs a doubling and
+     * each non-zero digit one addition of a table entry or its negation
+     *
+     * A w-NAF has about one non-zero digit in w + 1, against one set bit in 2 for plain
+     * double-and-add, at the price of 2^(w-2) - 1 additions for the table
+     */
+    pub fn scalar_mul_wnaf(&self, scalar: u32, width: u8) -> Result<Self, CryptoError> {
+        let digits = wnaf(scalar, width)?;
 
-    use super::*;
+        let base = Jacobian::from_affine(self);
+        let twice = base.double(&self.a);
+        let mut odd_multiples = vec![base];
+        for i in 1..(1_usize << (width - 2)) {
+            odd_multiples.push(odd_multiples[i - 1].add(&twice, &self.a));
+        }
 
-    const ORDER: u32 = 7;
-    const ORDER_2: u32 = 11;
+        let mut product = Jacobian::infinity(&self.a);
 
-    const SECP256K1_A: Fp = Fp {
-        num: 0,
-        modulus: ORDER,
-    };
-    const SECP256K1_B: Fp = Fp {
-        num: 7 % ORDER,
-        modulus: ORDER,
-    };
+        for &digit in digits.iter().rev() {
+            product = product.double(&self.a);
 
-    #[test]
-    fn test_point_init_error_infinity() {
-        let x = Fp::new(0, ORDER_2).unwrap();
-        let y = Fp::new(0, ORDER_2).unwrap();
+            let multiple = &odd_multiples[digit.unsigned_abs() as usize / 2];
+            if digit > 0 {
+                product = product.add(multiple, &self.a);
+            } else if digit < 0 {
+                product = product.add(&multiple.neg(), &self.a);
+            }
+        }
 
-        assert_eq!(
-            Point::new(SECP256K1_A, SECP256K1_B, Some(x), None),
-            Err(format!("Invalid infinity point"))
-        );
-        assert_eq!(
-            Point::new(SECP256K1_A, SECP256K1_B, None, Some(y)),
-            Err(format!("Invalid infinity point"))
-        );
+        Ok(product.to_affine(self.a.clone(), self.b.clone()))
     }
 
-    #[test]
-    fn test_point_init_error() {
-        let x = Fp::new(0, ORDER).unwrap();
-        let y = Fp::new(1, ORDER).unwrap();
+    /*
+     * `scalar_mul` for scalars of any width (secp256k1 private keys are 256-bit), the same
+     * double-and-add over the bits of `scalar` from the most significant one down
+     */
+    #[cfg(feature = "bigint")]
+    pub fn scalar_mul_big(&self, scalar: &num_bigint::BigUint) -> Self {
+        let base = Jacobian::from_affine(self);
+        let mut product = Jacobian::infinity(&self.a);
 
-        assert_eq!(
-            Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)),
-            Err(format!("coordinates ({}, {}) is not on the curve", x, y))
-        );
-    }
+        for bit in (0..scalar.bits()).rev() {
+            product = product.double(&self.a);
 
-    #[test]
-    fn test_point_init() {
-        let x = Fp::new(0, ORDER).unwrap();
-        let y = Fp::new(0, ORDER).unwrap();
+            if scalar.bit(bit) {
+                product = product.add(&base, &self.a);
+            }
+        }
 
-        assert_eq!(
-            Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)),
-            Ok(Point {
-                a: SECP256K1_A,
-                b: SECP256K1_B,
-                x: Some(x),
-                y: Some(y)
-            })
-        );
+        product.to_affine(self.a.clone(), self.b.clone())
     }
 
-    #[test]
-    fn test_point_init_infinity() {
-        let x = None;
-        let y = None;
+    /*
+     * `scalar_mul_big` on k + r·n for a fresh random r, where `n` is the order of the point
+     * (n·P = P(∞)), so (k + r·n)·P = k·P while the bits actually walked change with every
+     * call. Power traces of several multiplications by the same secret k then no longer
+     * line up bit for bit
+     *
+     * r has 64 bits, the usual choice: enough to hide the pattern, cheap in extra doublings
+     */
+    #[cfg(feature = "rand")]
+    pub fn scalar_mul_blinded<R: rand::Rng>(
+        &self,
+        scalar: &num_bigint::BigUint,
+        n: &num_bigint::BigUint,
+        rng: &mut R,
+    ) -> Self {
+        let blinding = num_bigint::BigUint::from(rng.gen::<u64>());
 
-        assert_eq!(
-            Point::new(SECP256K1_A, SECP256K1_B, x, y),
-            Ok(Point {
-                a: SECP256K1_A,
-                b: SECP256K1_B,
-                x: None,
-                y: None
-            })
-        );
+        self.scalar_mul_big(&(scalar + blinding * n))
     }
 
-    #[test]
-    fn test_point_addition_distinct() {
-        let x1 = Fp::new(1, ORDER).unwrap();
-        let y1 = Fp::new(6, ORDER).unwrap();
-        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x1), Some(y1)).unwrap();
+    /*
+     * Whether n·P = P(∞), i.e. the point lies in the subgroup of prime order `n`. Checked on
+     * parsed keys to rule out small-subgroup attacks
+     *
+     * For secp256k1 the cofactor is 1 (G generates the whole group) so every point on the
+     * curve qualifies, but on curves with cofactor h > 1 there are points of order dividing
+     * h·n that are not in the subgroup
+     */
+    #[cfg(feature = "bigint")]
+    pub fn is_in_subgroup(&self, n: &num_bigint::BigUint) -> bool {
+        self.scalar_mul_big(n).is_infinity()
+    }
 
-        let x2 = Fp::new(2, ORDER).unwrap();
-        let y2 = Fp::new(1, ORDER).unwrap();
-        let point_b = Point::new(SECP256K1_A, SECP256K1_B, Some(x2), Some(y2)).unwrap();
+    /*
+     * u·G + v·P in a single pass (Shamir's trick / Straus): both scalars are walked bit
+     * by bit together so the doublings are shared, with G + P precomputed for the bits
+     * set in both. ECDSA verification is exactly this shape
+     */
+    pub fn mul_add(g: Self, u: u32, p: Self, v: u32) -> Result<Self, CryptoError> {
+        if g.a != p.a || g.b != p.b {
+            return Err(CryptoError::DifferentCurves);
+        }
 
-        assert_eq!(
-            point_a + point_b,
-            Ok(Point {
-                a: SECP256K1_A,
-                b: SECP256K1_B,
-                x: Some(Fp::new(1, ORDER).unwrap()),
-                y: Some(Fp::new(1, ORDER).unwrap()),
-            })
-        )
-    }
+        let both = (g.clone() + p.clone())?;
+        let mut product = Point::infinity(g.a.clone(), g.b.clone());
 
-    #[test]
-    fn test_point_addition_equal() {
-        let x = Fp::new(1, ORDER).unwrap();
-        let y = Fp::new(6, ORDER).unwrap();
-        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+        for bit in (0..(u32::BITS - (u | v).leading_zeros())).rev() {
+            product = product.double()?;
 
-        println!("{:?}", point_a + point_a);
+            product = match ((u >> bit) & 1, (v >> bit) & 1) {
+                (1, 1) => (product + both.clone())?,
+                (1, 0) => (product + g.clone())?,
+                (0, 1) => (product + p.clone())?,
+                _ => product,
+            };
+        }
 
-        assert_eq!(
-            point_a + point_a,
-            Ok(Point {
-                a: SECP256K1_A,
-                b: SECP256K1_B,
-                x: Some(Fp::new(2, ORDER).unwrap()),
-                y: Some(Fp::new(6, ORDER).unwrap()),
-            })
-        )
+        Ok(product)
     }
 
-    #[test]
-    fn test_point_addition_equal_vertical_line() {
-        let x = Fp::new(0, ORDER).unwrap();
-        let y = Fp::new(0, ORDER).unwrap();
-        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+    /*
+     * P₁ + P₂ + ... + Pₙ, added pairwise in rounds: every round halves the list, and the
+     * slope denominators (x₂ - x₁, or 2y for a doubling) of all its pairs are inverted
+     * together with Montgomery's trick, one inversion per round instead of one per addition
+     *
+     * Pairs with P(∞) or opposite points need no slope and skip the batch
+     */
+    pub fn sum_points(points: &[Self]) -> Result<Self, CryptoError> {
+        let first = points.first().ok_or(CryptoError::EmptySum)?;
+        if points
+            .iter()
+            .any(|point| point.a != first.a || point.b != first.b)
+        {
+            return Err(CryptoError::DifferentCurves);
+        }
 
-        assert_eq!(
-            point_a + point_a,
-            Ok(Point {
-                a: SECP256K1_A,
-                b: SECP256K1_B,
-                x: None,
-                y: None,
-            })
-        )
-    }
+        let (a, b) = (first.a.clone(), first.b.clone());
+        let mut round = points.to_vec();
 
-    #[test]
-    fn test_point_addition_infinity() {
-        let x = None;
-        let y = None;
-        let point_a = Point::new(SECP256K1_A, SECP256K1_B, x, y).unwrap();
+        while round.len() > 1 {
+            let mut next = Vec::with_capacity(round.len().div_ceil(2));
+            // (x₁, y₁, x₂, slope numerator) for the pairs whose denominators are batched
+            let mut pending = Vec::new();
+            let mut denominators = Vec::new();
 
-        assert_eq!(
-            point_a + point_a,
-            Ok(Point {
-                a: SECP256K1_A,
-                b: SECP256K1_B,
-                x: None,
-                y: None,
-            })
-        )
-    }
+            for pair in round.chunks(2) {
+                let (p, q) = match pair {
+                    [p, q] => (p, q),
+                    _ => {
+                        next.push(pair[0].clone());
+                        continue;
+                    }
+                };
 
-    #[test]
-    fn test_point_addition_identity() {
+                let ((x1, y1), (x2, y2)) =
+                    match (p.x.clone().zip(p.y.clone()), q.x.clone().zip(q.y.clone())) {
+                        (None, _) => {
+                            next.push(q.clone());
+                            continue;
+                        }
+                        (_, None) => {
+                            next.push(p.clone());
+                            continue;
+                        }
+                        (Some(p), Some(q)) => (p, q),
+                    };
+
+                if x1 == x2 {
+                    // P + (-P), which also covers doubling a point with y = 0
+                    if (y1.clone() + y2.clone()).is_zero() {
+                        next.push(Point::infinity(a.clone(), b.clone()));
+                        continue;
+                    }
+
+                    denominators.push(y1.scalar_mul(2));
+                    pending.push((x1.clone(), y1, x2, x1.pow(2).scalar_mul(3) + a.clone()));
+                } else {
+                    denominators.push(x2.clone() - x1.clone());
+                    pending.push((x1, y1.clone(), x2, y2 - y1));
+                }
+            }
+
+            for ((x1, y1, x2, numerator), inverse) in
+                pending.into_iter().zip(batch_inverse(&denominators))
+            {
+                let slope = numerator * inverse;
+                let x3 = slope.pow(2) - x1.clone() - x2;
+                let y3 = slope * (x1 - x3.clone()) - y1;
+
+                next.push(Point::new(a.clone(), b.clone(), Some(x3), Some(y3))?);
+            }
+
+            round = next;
+        }
+
+        Ok(round.remove(0))
+    }
+
+    /*
+     * Montgomery ladder: a uniform sequence of group operations, NOT a constant-time
+     * implementation
+     *
+     * Keeps the invariant R₁ - R₀ = P and, for every one of the 32 bits of `scalar`
+     * (leading zeros included), performs exactly one addition and one doubling:
+     *
+     * bit = 0 => R₁ = R₀ + R₁;  R₀ = 2R₀
+     * bit = 1 => R₀ = R₀ + R₁;  R₁ = 2R₁
+     *
+     * so the sequence of group operations does not depend on the bits, unlike
+     * `scalar_mul` which skips the addition on unset bits and stops at the highest set bit.
+     * The operations themselves are the affine `Add` and `double`, which branch on the
+     * point at infinity, on P₁ == P₂ and on y = 0, and the field arithmetic under them is
+     * not constant-time either; the timing can still depend on the scalar, so this is no
+     * protection against side channels for secret scalars
+     */
+    pub fn scalar_mul_ladder(&self, scalar: u32) -> Self {
+        let mut r0 = Point::infinity(self.a.clone(), self.b.clone());
+        let mut r1 = self.clone();
+
+        for bit in (0..u32::BITS).rev() {
+            let swap = (scalar >> bit) & 1 == 1;
+
+            // double whichever of R₀, R₁ the bit picks
+            let sum = (r0.clone() + r1.clone()).unwrap();
+            let doubled = Self::select(&r0, &r1, swap).double().unwrap();
+
+            r0 = Self::select(&doubled, &sum, swap);
+            r1 = Self::select(&sum, &doubled, swap);
+        }
+
+        r0
+    }
+
+    /*
+     * `a` when `choice` is false, `b` when it is true
+     *
+     * NOT constant-time: it branches on `choice`, and the point at infinity and the
+     * field types underneath have no constant-time representation to blend. A select
+     * for secret data needs fixed-width limbs and a masked copy
+     */
+    pub fn select(a: &Self, b: &Self, choice: bool) -> Self {
+        if choice {
+            b.clone()
+        } else {
+            a.clone()
+        }
+    }
+
+    /*
+     * x(k·P) from x(P) alone: a Montgomery ladder on projective (X : Z) x-coordinates,
+     * so `y` is never computed (BIP340, ECDH). The point at infinity is (1 : 0)
+     *
+     * For R₀, R₁ with R₁ - R₀ = P (x-coordinate `x`):
+     *
+     * x(2R) = ((X² - aZ²)² - 8bXZ³) / 4Z(X³ + aXZ² + bZ³)
+     * x(R₀ + R₁) + x(R₁ - R₀) = (2(x₀ + x₁)(x₀x₁ + a) + 4b) / (x₀ - x₁)²
+     *
+     * `x` has to be the x-coordinate of a point on the curve, it is not checked.
+     * Errors when k·P is the point at infinity, which has no x-coordinate
+     */
+    pub fn x_only_mul(x: F, k: u128, a: F, b: F) -> Result<F, CryptoError> {
+        let double = |(x1, z1): (F, F)| {
+            let z1_2 = z1.pow(2);
+            let z1_3 = z1_2.clone() * z1.clone();
+            let x2 = (x1.pow(2) - a.clone() * z1_2.clone()).pow(2)
+                - (b.clone() * x1.clone() * z1_3.clone()).scalar_mul(8);
+            let z2 = (z1 * (x1.pow(3) + a.clone() * x1 * z1_2 + b.clone() * z1_3)).scalar_mul(4);
+
+            (x2, z2)
+        };
+
+        let differential_add = |(x0, z0): (F, F), (x1, z1): (F, F)| {
+            let cross = (x0.clone() * z1.clone() - x1.clone() * z0.clone()).pow(2);
+            let sum = ((x0.clone() * z1.clone() + x1.clone() * z0.clone())
+                * (x0 * x1 + a.clone() * z0.clone() * z1.clone()))
+            .scalar_mul(2)
+                + (b.clone() * z0.pow(2) * z1.pow(2)).scalar_mul(4);
+
+            (sum - x.clone() * cross.clone(), cross)
+        };
+
+        let mut r0 = (x.one(), x.zero());
+        let mut r1 = (x.clone(), x.one());
+
+        for bit in (0..u128::BITS).rev() {
+            if (k >> bit) & 1 == 1 {
+                r0 = differential_add(r0, r1.clone());
+                r1 = double(r1);
+            } else {
+                r1 = differential_add(r0.clone(), r1);
+                r0 = double(r0);
+            }
+        }
+
+        let (x_k, z_k) = r0;
+        if z_k.is_zero() {
+            return Err(CryptoError::XOnlyInfinity);
+        }
+
+        Ok(x_k / z_k)
+    }
+
+    /*
+     * Opt-in memoization for a base multiplied by many scalars, e.g. interactively: see
+     * `MulCache::multiply`. Nothing is computed until it is asked for
+     */
+    pub fn mul_cache(self) -> MulCache<F> {
+        MulCache {
+            base: self,
+            doublings: vec![],
+            products: BTreeMap::new(),
+        }
+    }
+
+    /*
+     * The multiples of `self` up to and including the first P(∞), so as many items as
+     * the order of the point. P(∞) itself generates the trivial group and yields only P(∞)
+     */
+    pub fn subgroup(self) -> SubgroupIter<F> {
+        SubgroupIter {
+            base: self.clone(),
+            next: Some(self),
+        }
+    }
+
+    // `Display` with the curve name in front, e.g. "secp256k1 • (x, y)"
+    pub fn display_with<'a>(&'a self, params: &'a CurveParams<F>) -> impl Display + 'a {
+        LabeledPoint {
+            point: self,
+            params,
+        }
+    }
+
+    // `x` and `y` both being `None` is the point at infinity P(∞)
+    #[allow(dead_code)]
+    pub fn is_infinity(&self) -> bool {
+        self.x.is_none() && self.y.is_none()
+    }
+
+    // (x, y), `None` for P(∞)
+    pub fn coordinates(&self) -> Option<(F, F)> {
+        match (&self.x, &self.y) {
+            (Some(x), Some(y)) => Some((x.clone(), y.clone())),
+            _ => None,
+        }
+    }
+
+    /*
+     * Whether `self` and `other` are the same point as far as their coordinates go: P(∞)
+     * matches only P(∞) of the same curve (it has no coordinates to compare), finite
+     * points are compared by (x, y) alone. `==` also compares `a` and `b`, so it tells
+     * apart the same (x, y) lying on two different curves, this does not
+     */
+    pub fn same_point(&self, other: &Self) -> bool {
+        match (self.coordinates(), other.coordinates()) {
+            (None, None) => self.a == other.a && self.b == other.b,
+            (Some(p), Some(q)) => p == q,
+            _ => false,
+        }
+    }
+
+    pub fn a(&self) -> F {
+        self.a.clone()
+    }
+
+    pub fn b(&self) -> F {
+        self.b.clone()
+    }
+
+    // whether (x, y) satisfies y² = x³ + ax + b
+    pub fn is_on_curve(x: &F, y: &F, a: &F, b: &F) -> bool {
+        let (lhs, rhs) = Self::curve_sides(x, y, a, b);
+
+        lhs == rhs
+    }
+
+    // (y², x³ + ax + b) at (x, y)
+    fn curve_sides(x: &F, y: &F, a: &F, b: &F) -> (F, F) {
+        let lhs = y.pow(2);
+        let rhs_0 = x.pow(3);
+        let rhs_1 = a.clone() * x.clone();
+        let rhs_01 = rhs_0 + rhs_1;
+        let rhs = rhs_01 + b.clone();
+
+        (lhs, rhs)
+    }
+}
+
+impl<F: PrimeField> Point<F> {
+    /*
+     * The two points with x-coordinate `x`: y² = x³ + ax + b has the roots `y` and `-y`
+     * (the same point twice when y = 0), or none when x³ + ax + b is not a square
+     */
+    pub fn from_x(x: F, a: F, b: F) -> Result<(Self, Self), CryptoError> {
+        let rhs = x.pow(3) + a.clone() * x.clone() + b.clone();
+        let y = rhs
+            .sqrt()
+            .map_err(|_| CryptoError::NotXCoordinate { x: x.to_string() })?;
+
+        Ok((
+            Point::new(a.clone(), b.clone(), Some(x.clone()), Some(y.clone()))?,
+            Point::new(a, b, Some(x), Some(-y))?,
+        ))
+    }
+
+    /*
+     * Whether y is even, read off the stored coordinate (nothing is recomputed); what the
+     * compressed SEC prefix and recovery ids encode. `None` for P(∞)
+     */
+    pub fn y_is_even(&self) -> Option<bool> {
+        self.y.as_ref().map(|y| !y.is_odd())
+    }
+
+    /*
+     * SEC (Standards for Efficient Cryptography) encoding:
+     *
+     * uncompressed: 0x04 || x || y
+     * compressed:   0x02 || x  when y is even
+     *               0x03 || x  when y is odd
+     *
+     * `x` and `y` are big-endian and as wide as the field (32 bytes for secp256k1). The
+     * point at infinity is the single byte 0x00
+     */
+    pub fn to_sec(&self, compressed: bool) -> Vec<u8> {
+        let (x, y) = match (&self.x, &self.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return vec![0x00],
+        };
+
+        let mut sec = Vec::new();
+
+        if compressed {
+            sec.push(if self.y_is_even() == Some(true) {
+                0x02
+            } else {
+                0x03
+            });
+            sec.extend(x.to_be_bytes());
+        } else {
+            sec.push(0x04);
+            sec.extend(x.to_be_bytes());
+            sec.extend(y.to_be_bytes());
+        }
+
+        sec
+    }
+
+    // `to_sec` as lowercase hex, the form block explorers and test vectors use
+    pub fn to_hex(&self, compressed: bool) -> String {
+        hex::encode(self.to_sec(compressed))
+    }
+
+    // the inverse of `to_sec`; compressed points are recovered with `from_x`
+    pub fn parse_sec(bytes: &[u8], a: F, b: F) -> Result<Self, CryptoError> {
+        let width = a.to_be_bytes().len();
+        let prefix = *bytes.first().ok_or(CryptoError::EmptySec)?;
+
+        let expected_length = match prefix {
+            0x00 => 1,
+            0x02 | 0x03 => 1 + width,
+            0x04 => 1 + 2 * width,
+            _ => return Err(CryptoError::InvalidSecPrefix(prefix)),
+        };
+
+        if bytes.len() != expected_length {
+            return Err(CryptoError::InvalidSecLength {
+                length: bytes.len(),
+                prefix,
+                expected: expected_length,
+            });
+        }
+
+        if prefix == 0x00 {
+            return Ok(Point::infinity(a, b));
+        }
+
+        let coordinate = |bytes: &[u8]| {
+            a.with_be_bytes(bytes)
+                .map_err(|error| CryptoError::InvalidSecCoordinate(Box::new(error)))
+        };
+
+        let x = coordinate(&bytes[1..1 + width])?;
+
+        if prefix == 0x04 {
+            let y = coordinate(&bytes[1 + width..])?;
+
+            return Point::new(a, b, Some(x), Some(y));
+        }
+
+        let (point, negated) = Point::from_x(x, a, b)?;
+        if point.y_is_even() == Some(prefix == 0x02) {
+            Ok(point)
+        } else {
+            Ok(negated)
+        }
+    }
+}
+
+impl Point<Fp> {
+    /*
+     * Every point of y² = x³ + ax + b over 𝔽p: P(∞), then for each x in [0, p) the points
+     * with y = ±√(x³ + ax + b), when the square root exists. One point when it is 0, y = -y
+     *
+     * That is p square root attempts, meant for the small fields of the exercises
+     */
+    pub fn all_points(a: Fp, b: Fp) -> Result<Vec<Self>, CryptoError> {
+        if a.modulus != b.modulus {
+            return Err(CryptoError::DifferentFields {
+                operation: "define a curve with",
+            });
+        }
+
+        let mut points = vec![Self::infinity(a, b)];
+
+        for num in 0..a.modulus {
+            let x = a.with_num(num)?;
+            let Ok(y) = (x.pow(3) + a * x + b).sqrt() else {
+                continue;
+            };
+
+            let (low, high) = if y.num <= (-y).num { (y, -y) } else { (-y, y) };
+            points.push(Self::new(a, b, Some(x), Some(low))?);
+            if high != low {
+                points.push(Self::new(a, b, Some(x), Some(high))?);
+            }
+        }
+
+        Ok(points)
+    }
+
+    // #E(𝔽p), P(∞) included, by counting `all_points`
+    pub fn curve_order(a: Fp, b: Fp) -> Result<u32, CryptoError> {
+        Ok(Self::all_points(a, b)?.len() as u32)
+    }
+
+    /*
+     * The order of the point: the smallest `n` such that n·P = P(∞), found by adding P to
+     * itself until the identity comes up
+     *
+     * By Hasse's theorem #E(𝔽p) ≤ p + 1 + 2√p, and the order of a point divides the order
+     * of the group, so running past that bound means P is not a point of a (non-singular)
+     * curve group and an error is returned instead of looping forever
+     */
+    pub fn order(&self) -> Result<u32, CryptoError> {
+        let modulus = self.a.modulus as u64;
+        let hasse_bound = modulus + 1 + 2 * ((modulus as f64).sqrt().ceil() as u64);
+
+        let mut multiple = *self;
+        let mut n: u64 = 1;
+
+        while !multiple.is_infinity() {
+            if n >= hasse_bound {
+                return Err(CryptoError::HasseBoundExceeded {
+                    bound: hasse_bound,
+                    prime: modulus,
+                });
+            }
+
+            multiple = (multiple + *self)?;
+            n += 1;
+        }
+
+        Ok(n as u32)
+    }
+}
+
+impl<F: Field> PrecomputedPoint<F> {
+    pub fn new(base: Point<F>, window: u8) -> Result<Self, CryptoError> {
+        if !(1..=8).contains(&window) {
+            return Err(CryptoError::InvalidWindow(window));
+        }
+
+        let mut table = vec![Point::infinity(base.a.clone(), base.b.clone())];
+
+        for i in 1..(1_usize << window) {
+            table.push((table[i - 1].clone() + base.clone())?);
+        }
+
+        Ok(PrecomputedPoint { window, table })
+    }
+
+    /*
+     * Fixed-window scalar multiplication: `scalar` is cut into `window`-bit digits and,
+     * from the most significant digit down, the running result is doubled `window` times
+     * and the table entry for the digit is added. Only the doublings are computed per call
+     * and there is at most one addition per digit, rather than one per set bit
+     *
+     * e.g. window = 2, 45 = 0b10_11_01 => ((2·base)·4 + 3·base)·4 + 1·base
+     */
+    pub fn multiply(&self, scalar: u32) -> Point<F> {
+        let window = self.window as u32;
+        let mask = (1_u32 << window) - 1;
+        let digits = (u32::BITS - scalar.leading_zeros()).div_ceil(window);
+
+        let mut product = self.table[0].clone();
+
+        for digit in (0..digits).rev() {
+            for _ in 0..window {
+                product = product.double().unwrap();
+            }
+
+            let index = ((scalar >> (digit * window)) & mask) as usize;
+            if index != 0 {
+                product = (product + self.table[index].clone()).unwrap();
+            }
+        }
+
+        product
+    }
+}
+
+impl<F: Field> MulCache<F> {
+    /*
+     * scalar·base, straight from the cache when `scalar` was seen before. Otherwise the
+     * 2^i·base for the set bits are added up, with any doublings still missing computed
+     * and kept on the way, and the result is remembered too. Every distinct scalar stays
+     * in memory for as long as the cache does
+     */
+    pub fn multiply(&mut self, scalar: u32) -> Point<F> {
+        if let Some(product) = self.products.get(&scalar) {
+            return product.clone();
+        }
+
+        let bits = (u32::BITS - scalar.leading_zeros()) as usize;
+        while self.doublings.len() < bits {
+            let next = match self.doublings.last() {
+                Some(last) => last.double().unwrap(),
+                None => self.base.clone(),
+            };
+            self.doublings.push(next);
+        }
+
+        let mut product = Point::infinity(self.base.a.clone(), self.base.b.clone());
+        for (bit, doubling) in self.doublings[..bits].iter().enumerate() {
+            if (scalar >> bit) & 1 == 1 {
+                product = (product + doubling.clone()).unwrap();
+            }
+        }
+
+        self.products.insert(scalar, product.clone());
+        product
+    }
+
+    // how many distinct scalars have been answered, and so are cached
+    pub fn len(&self) -> usize {
+        self.products.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.products.is_empty()
+    }
+}
+
+impl<F: Field> ops::Add for Point<F> {
+    type Output = Result<Self, CryptoError>;
+
+    fn add(self, point_2: Self) -> Self::Output {
+        if self.a != point_2.a || self.b != point_2.b {
+            return Err(CryptoError::DifferentCurves);
+        }
+
+        // `new` keeps a point within one field, but the fields are public; as in `new`,
+        // comparing ZEROs compares fields
+        let field = self.a.zero();
+        if [&self.b]
+            .into_iter()
+            .chain(self.x.iter().chain(&self.y))
+            .chain(point_2.x.iter().chain(&point_2.y))
+            .any(|element| element.zero() != field)
+        {
+            return Err(CryptoError::MixedFields);
+        }
+
+        /*
+         * Case 1(a): first point is at infinity P₁ = P(∞), the identity: P(∞) + P₂ = P₂
+         */
+        if self.is_infinity() {
+            return Ok(point_2);
+        }
+        /*
+         * Case 1(b): second point is at infinity P₂ = P(∞), the identity: P₁ + P(∞) = P₁
+         */
+        else if point_2.is_infinity() {
+            return Ok(self);
+        }
+
+        let x1_value = self.x.clone().unwrap();
+        let y1_value = self.y.clone().unwrap();
+        let x2_value = point_2.x.clone().unwrap();
+        let y2_value = point_2.y.clone().unwrap();
+
+        /*
+         * Case 2: same points where P₁ == P₂
+         * Same formula as Case 3, except:
+         *
+         * s (slope) = (3x² + a)/2y;  | dy/dx => y² = x³ + ax + b
+         */
+        let slope = if self.x == point_2.x && self.y == point_2.y {
+            /*
+             * Case 2 (variant): same points where P₁ == P₂ and `y` = 0; `s` denominator results in zero
+             * meaning slope is `undefined`. This results in P(∞)
+             */
+            if y1_value.is_zero() {
+                return Ok(Point::infinity(self.a, self.b));
+            }
+
+            (x1_value.pow(2).scalar_mul(3) + self.a.clone()) / y1_value.scalar_mul(2)
+        }
+        /*
+         * Case 3 (base case): distinct points where P₁ != P₂
+         * P₁ = (x₁, y₁);  P₂ = (x₂, y₂);  P₃ = (x₃, y₃)
+         * P₁ + P₂ = P₃
+         *
+         * s (slope) = (y₂ - y₁) / (x₂ - x₁)
+         * x₃ = s² - x₁ - x₂
+         * y₃ = s(x₁ - x₃) - y₁
+         */
+        else {
+            /*
+             * Case 3 (variant) - if the two `x` points are equivalent and `y` points are negated, i.e point_a.x == point_b.x && point_a.y == -(point_b.y)
+             * This results in the infinity point
+             */
+            if self.x == point_2.x && (y1_value.clone() + y2_value.clone()).is_zero() {
+                return Ok(Point::infinity(self.a, self.b));
+            }
+
+            (y2_value - y1_value.clone()) / (x2_value.clone() - x1_value.clone())
+        };
+
+        let point_3_x = (slope.pow(2) - x1_value.clone()) - x2_value;
+        let point_3_y = (slope * (x1_value - point_3_x.clone())) - y1_value;
+
+        Point::new(self.a, self.b, Some(point_3_x), Some(point_3_y))
+    }
+}
+
+/*
+ * -P = (x, -y), the reflection of P over the x-axis; -P(∞) = P(∞)
+ */
+impl<F: Field> ops::Neg for Point<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            y: self.y.map(|y| -y),
+            ..self
+        }
+    }
+}
+
+/*
+ * P₁ - P₂ = P₁ + (-P₂)
+ */
+impl<F: Field> ops::Sub for Point<F> {
+    type Output = Result<Self, CryptoError>;
+
+    fn sub(self, point_2: Self) -> Self::Output {
+        self + (-point_2)
+    }
+}
+
+/*
+ * `&P₁ + &P₂` and `&P₁ - &P₂`, for points over big fields that are not `Copy`: the
+ * operands are left with the caller, only what the arithmetic consumes is cloned
+ */
+impl<F: Field> ops::Add<&Point<F>> for &Point<F> {
+    type Output = Result<Point<F>, CryptoError>;
+
+    fn add(self, point_2: &Point<F>) -> Self::Output {
+        self.clone() + point_2.clone()
+    }
+}
+
+impl<F: Field> ops::Sub<&Point<F>> for &Point<F> {
+    type Output = Result<Point<F>, CryptoError>;
+
+    fn sub(self, point_2: &Point<F>) -> Self::Output {
+        self.clone() - point_2.clone()
+    }
+}
+
+/*
+ * n·P in the book's notation, `P * n` and `n * P` are both `P.scalar_mul(n)`
+ */
+impl<F: Field> ops::Mul<u32> for Point<F> {
+    type Output = Self;
+
+    fn mul(self, by: u32) -> Self::Output {
+        self.scalar_mul(by)
+    }
+}
+
+impl<F: Field> ops::Mul<Point<F>> for u32 {
+    type Output = Point<F>;
+
+    fn mul(self, point: Point<F>) -> Self::Output {
+        point.scalar_mul(self)
+    }
+}
+
+impl<F: Field> fmt::Display for Point<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.x, &self.y) {
+            (Some(x), Some(y)) => write!(f, "• ({}, {})", x, y),
+            _ => write!(f, "• (∞, ∞)"),
+        }
+    }
+}
+
+// `{:x}` is the compressed SEC encoding, `to_hex(false)` gives the uncompressed one
+impl<F: PrimeField> fmt::LowerHex for Point<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_hex(true))
+    }
+}
+
+/*
+ * {"a": .., "b": .., "sec": "<uncompressed SEC hex>"}, the curve is needed to parse the
+ * SEC bytes back and the point is checked to be on it
+ */
+#[cfg(feature = "serde")]
+impl<F: PrimeField + serde::Serialize> serde::Serialize for Point<F> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Point", 3)?;
+        state.serialize_field("a", &self.a)?;
+        state.serialize_field("b", &self.b)?;
+        state.serialize_field("sec", &self.to_hex(false))?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: PrimeField + serde::Deserialize<'de>> serde::Deserialize<'de> for Point<F> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Fields<F> {
+            a: F,
+            b: F,
+            sec: String,
+        }
+
+        let fields = Fields::<F>::deserialize(deserializer)?;
+        let sec = hex::decode(&fields.sec).map_err(serde::de::Error::custom)?;
+
+        Point::parse_sec(&sec, fields.a, fields.b).map_err(serde::de::Error::custom)
+    }
+}
+
+/*
+ * The width-w non-adjacent form of `scalar`, least significant digit first: digits are 0
+ * or odd with |d| < 2^(w-1), and of any w consecutive digits at most one is non-zero
+ *
+ * While k is odd, d = k mods 2^w (the residue in (-2^(w-1), 2^(w-1)]) is taken and k - d,
+ * now divisible by 2^w, guarantees the next w - 1 digits are zeros
+ *
+ * e.g. w = 2, 7 = 0b111 => [-1, 0, 0, 1] (8 - 1)
+ *
+ * `width` has to be in 2..=8: w = 1 leaves no odd digit below 2^(w-1) = 1, and past 8 the
+ * digits no longer fit in an i8
+ */
+pub fn wnaf(scalar: u32, width: u8) -> Result<Vec<i8>, CryptoError> {
+    if !(2..=8).contains(&width) {
+        return Err(CryptoError::InvalidNafWidth(width));
+    }
+
+    let modulus = 1_i64 << width;
+    let mut k = scalar as i64;
+    let mut digits = Vec::new();
+
+    while k > 0 {
+        let mut digit = 0;
+
+        if k & 1 == 1 {
+            digit = k & (modulus - 1);
+            if digit >= modulus / 2 {
+                digit -= modulus;
+            }
+            k -= digit;
+        }
+
+        digits.push(digit as i8);
+        k >>= 1;
+    }
+
+    Ok(digits)
+}
+
+/*
+ * Montgomery's trick: the inverses of n non-zero elements with a single inversion
+ *
+ * With the prefix products cᵢ = d₀·d₁·...·dᵢ, one inversion gives cₙ₋₁⁻¹ and then, walking
+ * back, dᵢ⁻¹ = cᵢ⁻¹·cᵢ₋₁ and cᵢ₋₁⁻¹ = cᵢ⁻¹·dᵢ, i.e. 3(n - 1) multiplications
+ */
+fn batch_inverse<F: Field>(elements: &[F]) -> Vec<F> {
+    let Some(first) = elements.first() else {
+        return Vec::new();
+    };
+
+    let mut prefix = Vec::with_capacity(elements.len());
+    prefix.push(first.clone());
+    for element in &elements[1..] {
+        prefix.push(prefix[prefix.len() - 1].clone() * element.clone());
+    }
+
+    let mut inverses = vec![first.zero(); elements.len()];
+    let mut running = prefix[elements.len() - 1].mul_inverse();
+
+    for i in (1..elements.len()).rev() {
+        inverses[i] = running.clone() * prefix[i - 1].clone();
+        running = running * elements[i].clone();
+    }
+    inverses[0] = running;
+
+    inverses
+}
+
+impl<F: Field> Jacobian<F> {
+    // (1 : 1 : 0), `field` is any element of the field the coordinates live in
+    fn infinity(field: &F) -> Self {
+        Self {
+            x: field.one(),
+            y: field.one(),
+            z: field.zero(),
+        }
+    }
+
+    // (x : y : 1)
+    fn from_affine(point: &Point<F>) -> Self {
+        match (&point.x, &point.y) {
+            (Some(x), Some(y)) => Self {
+                x: x.clone(),
+                y: y.clone(),
+                z: x.one(),
+            },
+            _ => Self::infinity(&point.a),
+        }
+    }
+
+    fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    // -(X : Y : Z) = (X : -Y : Z)
+    fn neg(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: -self.y.clone(),
+            z: self.z.clone(),
+        }
+    }
+
+    // x = X/Z², y = Y/Z³, with the one inversion of Z
+    fn to_affine(&self, a: F, b: F) -> Point<F> {
+        if self.is_infinity() {
+            return Point::infinity(a, b);
+        }
+
+        let z_inverse = self.z.mul_inverse();
+        let z_inverse_squared = z_inverse.pow(2);
+
+        Point {
+            a,
+            b,
+            x: Some(self.x.clone() * z_inverse_squared.clone()),
+            y: Some(self.y.clone() * z_inverse_squared * z_inverse),
+        }
+    }
+
+    /*
+     * S = 4·X·Y², M = 3·X² + a·Z⁴
+     * X₃ = M² - 2S
+     * Y₃ = M(S - X₃) - 8·Y⁴
+     * Z₃ = 2·Y·Z
+     *
+     * Y = 0 is a vertical tangent, 2P = P(∞)
+     */
+    fn double(&self, a: &F) -> Self {
+        if self.is_infinity() || self.y.is_zero() {
+            return Self::infinity(a);
+        }
+
+        let y_squared = self.y.pow(2);
+        let s = (self.x.clone() * y_squared.clone()).scalar_mul(4);
+        let m = self.x.pow(2).scalar_mul(3) + a.clone() * self.z.pow(4);
+
+        let x3 = m.pow(2) - s.scalar_mul(2);
+        let y3 = m * (s - x3.clone()) - y_squared.pow(2).scalar_mul(8);
+        let z3 = (self.y.clone() * self.z.clone()).scalar_mul(2);
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /*
+     * U₁ = X₁·Z₂², U₂ = X₂·Z₁², S₁ = Y₁·Z₂³, S₂ = Y₂·Z₁³ (both points over a common Z)
+     * H = U₂ - U₁, R = S₂ - S₁
+     * X₃ = R² - H³ - 2·U₁·H²
+     * Y₃ = R(U₁·H² - X₃) - S₁·H³
+     * Z₃ = H·Z₁·Z₂
+     *
+     * H = 0 means equal x: the same point (double) or opposite points (P(∞))
+     */
+    fn add(&self, other: &Self, a: &F) -> Self {
+        if self.is_infinity() {
+            return other.clone();
+        }
+        if other.is_infinity() {
+            return self.clone();
+        }
+
+        let z1_squared = self.z.pow(2);
+        let z2_squared = other.z.pow(2);
+        let u1 = self.x.clone() * z2_squared.clone();
+        let u2 = other.x.clone() * z1_squared.clone();
+        let s1 = self.y.clone() * z2_squared * other.z.clone();
+        let s2 = other.y.clone() * z1_squared * self.z.clone();
+
+        if u1 == u2 {
+            return if s1 == s2 {
+                self.double(a)
+            } else {
+                Self::infinity(a)
+            };
+        }
+
+        let h = u2 - u1.clone();
+        let r = s2 - s1.clone();
+        let h_squared = h.pow(2);
+        let h_cubed = h_squared.clone() * h.clone();
+        let u1_h_squared = u1 * h_squared;
+
+        let x3 = r.pow(2) - h_cubed.clone() - u1_h_squared.scalar_mul(2);
+        let y3 = r * (u1_h_squared - x3.clone()) - s1 * h_cubed;
+        let z3 = h * self.z.clone() * other.z.clone();
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl<F: Field> Iterator for SubgroupIter<F> {
+    type Item = Point<F>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let multiple = self.next.take()?;
+
+        if !multiple.is_infinity() {
+            // the base and its multiples share a curve, so this can't fail
+            self.next = Some((multiple.clone() + self.base.clone()).unwrap());
+        }
+
+        Some(multiple)
+    }
+}
+
+struct LabeledPoint<'a, F> {
+    point: &'a Point<F>,
+    params: &'a CurveParams<F>,
+}
+
+impl<F: Field> fmt::Display for LabeledPoint<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.params.name, self.point)
+    }
+}
+
+#[cfg(test)]
+mod ecc_tests {
+    use super::*;
+
+    const ORDER: u32 = 7;
+    const ORDER_2: u32 = 11;
+
+    const SECP256K1_A: Fp = Fp {
+        num: 0,
+        modulus: ORDER,
+    };
+    const SECP256K1_B: Fp = Fp {
+        num: 7 % ORDER,
+        modulus: ORDER,
+    };
+
+    #[test]
+    fn test_point_init_error_infinity() {
+        let x = Fp::new(0, ORDER).unwrap();
+        let y = Fp::new(0, ORDER).unwrap();
+
+        assert_eq!(
+            Point::new(SECP256K1_A, SECP256K1_B, Some(x), None),
+            Err(CryptoError::InvalidInfinity)
+        );
+        assert_eq!(
+            Point::new(SECP256K1_A, SECP256K1_B, None, Some(y)),
+            Err(CryptoError::InvalidInfinity)
+        );
+    }
+
+    #[test]
+    fn test_point_init_error_different_fields() {
+        let x = Fp::new(0, ORDER_2).unwrap();
+        let y = Fp::new(0, ORDER_2).unwrap();
+        let error = Err(CryptoError::DifferentFields {
+            operation: "define a point with",
+        });
+
+        // reported ahead of the half-infinity shape
+        assert_eq!(Point::new(SECP256K1_A, SECP256K1_B, Some(x), None), error);
+        assert_eq!(Point::new(SECP256K1_A, SECP256K1_B, None, Some(y)), error);
+        assert_eq!(
+            Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)),
+            error
+        );
+        assert_eq!(
+            Point::new(SECP256K1_A, Fp::new(7, ORDER_2).unwrap(), None, None),
+            error
+        );
+    }
+
+    #[test]
+    fn test_point_init_error() {
+        let x = Fp::new(0, ORDER).unwrap();
+        let y = Fp::new(1, ORDER).unwrap();
+
+        assert_eq!(
+            Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)),
+            Err(CryptoError::NotOnCurve {
+                x: x.to_string(),
+                y: y.to_string(),
+                lhs: y.pow(2).to_string(),
+                rhs: SECP256K1_B.to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_point_init() {
+        let x = Fp::new(0, ORDER).unwrap();
+        let y = Fp::new(0, ORDER).unwrap();
+
+        assert_eq!(
+            Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)),
+            Ok(Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: Some(x),
+                y: Some(y)
+            })
+        );
+    }
+
+    #[test]
+    fn test_point_init_infinity() {
+        let x = None;
+        let y = None;
+
+        assert_eq!(
+            Point::new(SECP256K1_A, SECP256K1_B, x, y),
+            Ok(Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: None,
+                y: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_point_addition_distinct() {
+        let x1 = Fp::new(1, ORDER).unwrap();
+        let y1 = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x1), Some(y1)).unwrap();
+
+        let x2 = Fp::new(2, ORDER).unwrap();
+        let y2 = Fp::new(1, ORDER).unwrap();
+        let point_b = Point::new(SECP256K1_A, SECP256K1_B, Some(x2), Some(y2)).unwrap();
+
+        assert_eq!(
+            point_a + point_b,
+            Ok(Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: Some(Fp::new(1, ORDER).unwrap()),
+                y: Some(Fp::new(1, ORDER).unwrap()),
+            })
+        )
+    }
+
+    #[test]
+    fn test_point_addition_equal() {
+        let x = Fp::new(1, ORDER).unwrap();
+        let y = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+
+        println!("{:?}", point_a + point_a);
+
+        assert_eq!(
+            point_a + point_a,
+            Ok(Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: Some(Fp::new(2, ORDER).unwrap()),
+                y: Some(Fp::new(6, ORDER).unwrap()),
+            })
+        )
+    }
+
+    #[test]
+    fn test_point_addition_equal_vertical_line() {
+        let x = Fp::new(0, ORDER).unwrap();
+        let y = Fp::new(0, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+
+        assert_eq!(
+            point_a + point_a,
+            Ok(Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: None,
+                y: None,
+            })
+        )
+    }
+
+    #[test]
+    fn test_point_addition_infinity() {
+        let x = None;
+        let y = None;
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, x, y).unwrap();
+
+        assert_eq!(
+            point_a + point_a,
+            Ok(Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: None,
+                y: None,
+            })
+        )
+    }
+
+    #[test]
+    fn test_point_addition_identity() {
         let x = None;
         let y = None;
         let point_infinity = Point::new(SECP256K1_A, SECP256K1_B, x, y).unwrap();
 
-        let x1 = Fp::new(1, ORDER).unwrap();
-        let y1 = Fp::new(6, ORDER).unwrap();
-        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x1), Some(y1)).unwrap();
+        let x1 = Fp::new(1, ORDER).unwrap();
+        let y1 = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x1), Some(y1)).unwrap();
+
+        let x2 = Fp::new(4, ORDER).unwrap();
+        let y2 = Fp::new(1, ORDER).unwrap();
+        let point_b = Point::new(SECP256K1_A, SECP256K1_B, Some(x2), Some(y2)).unwrap();
+
+        assert_eq!(
+            point_infinity + point_a,
+            Ok(Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: Some(Fp::new(1, ORDER).unwrap()),
+                y: Some(Fp::new(6, ORDER).unwrap()),
+            })
+        );
+
+        assert_eq!(
+            point_b + point_infinity,
+            Ok(Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: Some(Fp::new(4, ORDER).unwrap()),
+                y: Some(Fp::new(1, ORDER).unwrap()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_point_addition_identity_variation() {
+        let x1 = Fp::new(1, ORDER).unwrap();
+        let y1 = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x1), Some(y1)).unwrap();
+
+        let x2 = Fp::new(1, ORDER).unwrap();
+        let y2 = Fp::new(1, ORDER).unwrap();
+        let point_b = Point::new(SECP256K1_A, SECP256K1_B, Some(x2), Some(y2)).unwrap();
+
+        assert_eq!(
+            point_a + point_b,
+            Ok(Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: None,
+                y: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let x = Fp::new(1, ORDER).unwrap();
+        let y = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+
+        assert_eq!(
+            point_a.scalar_mul(5),
+            Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: Some(Fp::new(2, ORDER).unwrap()),
+                y: Some(Fp::new(1, ORDER).unwrap()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_scalar_multiplication_matches_repeated_addition() {
+        let x = Fp::new(1, ORDER).unwrap();
+        let y = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+
+        let mut expected = Point::infinity(SECP256K1_A, SECP256K1_B);
+
+        for by in 0..=30 {
+            assert_eq!(point_a.scalar_mul(by), expected, "scalar: {}", by);
+            expected = (expected + point_a).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_scalar_multiplication_by_zero() {
+        let x = Fp::new(1, ORDER).unwrap();
+        let y = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+
+        assert_eq!(
+            point_a.scalar_mul(0),
+            Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: None,
+                y: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_scalar_multiplication_by_one() {
+        let x = Fp::new(1, ORDER).unwrap();
+        let y = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+
+        assert_eq!(point_a.scalar_mul(1), point_a);
+    }
+
+    #[test]
+    fn test_scalar_multiplication_of_infinity() {
+        let point_infinity = Point::infinity(SECP256K1_A, SECP256K1_B);
+
+        for by in (0..=64).chain([u32::MAX - 1, u32::MAX]) {
+            assert_eq!(
+                point_infinity.scalar_mul(by),
+                point_infinity,
+                "scalar: {}",
+                by
+            );
+        }
+    }
+
+    #[test]
+    fn test_scalar_multiplication_ladder() {
+        let x = Fp::new(1, ORDER).unwrap();
+        let y = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+
+        for by in 0..=40 {
+            assert_eq!(
+                point_a.scalar_mul_ladder(by),
+                point_a.scalar_mul(by),
+                "scalar: {}",
+                by
+            );
+        }
+    }
+
+    #[test]
+    fn test_point_negation() {
+        let x = Fp::new(1, ORDER).unwrap();
+        let y = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+        let point_infinity = Point::infinity(SECP256K1_A, SECP256K1_B);
+
+        assert_eq!(
+            -point_a,
+            Point {
+                a: SECP256K1_A,
+                b: SECP256K1_B,
+                x: Some(Fp::new(1, ORDER).unwrap()),
+                y: Some(Fp::new(1, ORDER).unwrap()),
+            }
+        );
+        assert_eq!(point_a + (-point_a), Ok(point_infinity));
+        assert_eq!(-point_infinity, point_infinity);
+    }
+
+    #[test]
+    fn test_point_subtraction() {
+        let x1 = Fp::new(1, ORDER).unwrap();
+        let y1 = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x1), Some(y1)).unwrap();
+
+        let x2 = Fp::new(2, ORDER).unwrap();
+        let y2 = Fp::new(1, ORDER).unwrap();
+        let point_b = Point::new(SECP256K1_A, SECP256K1_B, Some(x2), Some(y2)).unwrap();
+
+        assert_eq!(point_a - point_b, point_a + (-point_b));
+        assert_eq!(
+            point_a - point_a,
+            Point::new(SECP256K1_A, SECP256K1_B, None, None)
+        );
+    }
+
+    #[test]
+    fn test_point_double() {
+        let points = [(1, 6), (1, 1), (2, 1), (2, 6), (4, 1)].map(|(x, y)| {
+            Point::new(
+                SECP256K1_A,
+                SECP256K1_B,
+                Some(Fp::new(x, ORDER).unwrap()),
+                Some(Fp::new(y, ORDER).unwrap()),
+            )
+            .unwrap()
+        });
+
+        for point in points {
+            assert_eq!(point.double(), point + point, "point: {}", point);
+        }
+    }
+
+    #[test]
+    fn test_point_double_vertical_tangent() {
+        let x = Fp::new(0, ORDER).unwrap();
+        let y = Fp::new(0, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+        let point_infinity = Point::infinity(SECP256K1_A, SECP256K1_B);
+
+        assert_eq!(point_a.double(), point_a + point_a);
+        assert_eq!(point_a.double(), Ok(point_infinity));
+        assert_eq!(point_infinity.double(), Ok(point_infinity));
+    }
+
+    #[test]
+    fn test_x_only_multiplication() {
+        // y² = x³ + 7 over 𝔽223, (47, 71) generates a subgroup of order 21
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let x = Fp::new(47, prime).unwrap();
+        let y = Fp::new(71, prime).unwrap();
+        let point_a = Point::new(a, b, Some(x), Some(y)).unwrap();
+
+        for k in 1..=20 {
+            assert_eq!(
+                Point::x_only_mul(x, k, a, b),
+                Ok(point_a.scalar_mul(k as u32).x.unwrap()),
+                "scalar: {}",
+                k
+            );
+        }
+
+        let k = u128::MAX - 5;
+        assert_eq!(
+            Point::x_only_mul(x, k, a, b),
+            Ok(point_a.scalar_mul((k % 21) as u32).x.unwrap())
+        );
+    }
+
+    #[test]
+    fn test_x_only_multiplication_infinity() {
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let x = Fp::new(47, prime).unwrap();
+
+        for k in [0, 21, 42] {
+            assert_eq!(
+                Point::x_only_mul(x, k, a, b),
+                Err(CryptoError::XOnlyInfinity)
+            );
+        }
+    }
+
+    #[test]
+    fn test_point_is_infinity() {
+        let x = Fp::new(1, ORDER).unwrap();
+        let y = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+        let point_infinity = Point::infinity(SECP256K1_A, SECP256K1_B);
+
+        assert!(point_infinity.is_infinity());
+        assert!(!point_a.is_infinity());
+    }
+
+    #[test]
+    fn test_point_is_on_curve() {
+        let on_curve = [(0, 0), (1, 1), (1, 6), (2, 1), (4, 6)];
+        let off_curve = [(0, 1), (1, 2), (3, 3), (5, 0), (6, 6)];
+
+        for (x, y) in on_curve {
+            assert!(
+                Point::is_on_curve(
+                    &Fp::new(x, ORDER).unwrap(),
+                    &Fp::new(y, ORDER).unwrap(),
+                    &SECP256K1_A,
+                    &SECP256K1_B
+                ),
+                "({}, {})",
+                x,
+                y
+            );
+        }
+
+        for (x, y) in off_curve {
+            assert!(
+                !Point::is_on_curve(
+                    &Fp::new(x, ORDER).unwrap(),
+                    &Fp::new(y, ORDER).unwrap(),
+                    &SECP256K1_A,
+                    &SECP256K1_B
+                ),
+                "({}, {})",
+                x,
+                y
+            );
+        }
+    }
+
+    #[test]
+    fn test_point_infinity() {
+        let point_infinity = Point::infinity(SECP256K1_A, SECP256K1_B);
+
+        assert!(point_infinity.is_infinity());
+        assert_eq!(
+            Point::new(SECP256K1_A, SECP256K1_B, None, None),
+            Ok(point_infinity)
+        );
+    }
+
+    #[test]
+    fn test_point_infinity_identity() {
+        let point_infinity = Point::infinity(SECP256K1_A, SECP256K1_B);
+
+        let x = Fp::new(2, ORDER).unwrap();
+        let y = Fp::new(6, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+
+        assert_eq!(point_a + point_infinity, Ok(point_a));
+        assert_eq!(point_infinity + point_a, Ok(point_a));
+        assert_eq!(point_infinity + point_infinity, Ok(point_infinity));
+    }
+
+    #[test]
+    fn test_point_display_with_curve_params() {
+        let toy_curve = CurveParams {
+            name: "toy-7",
+            a: SECP256K1_A,
+            b: SECP256K1_B,
+        };
+        let book_curve = CurveParams {
+            name: "book-223",
+            a: Fp::new(0, 223).unwrap(),
+            b: Fp::new(7, 223).unwrap(),
+        };
+
+        let point_a = Point::new(
+            toy_curve.a,
+            toy_curve.b,
+            Some(Fp::new(1, ORDER).unwrap()),
+            Some(Fp::new(6, ORDER).unwrap()),
+        )
+        .unwrap();
+        let point_b = Point::new(
+            book_curve.a,
+            book_curve.b,
+            Some(Fp::new(47, 223).unwrap()),
+            Some(Fp::new(71, 223).unwrap()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            point_a.display_with(&toy_curve).to_string(),
+            "toy-7 • (1 — 𝔽7, 6 — 𝔽7)"
+        );
+        assert_eq!(
+            point_b.display_with(&book_curve).to_string(),
+            "book-223 • (47 — 𝔽223, 71 — 𝔽223)"
+        );
+        assert_eq!(
+            Point::infinity(book_curve.a, book_curve.b)
+                .display_with(&book_curve)
+                .to_string(),
+            "book-223 • (∞, ∞)"
+        );
+    }
+
+    #[test]
+    fn test_point_order() {
+        // y² = x³ + 7 over 𝔽223, orders from Programming Bitcoin chapter 3
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+
+        for (x, y, order) in [(15, 86, 7), (47, 71, 21), (17, 56, 42)] {
+            let point = Point::new(
+                a,
+                b,
+                Some(Fp::new(x, prime).unwrap()),
+                Some(Fp::new(y, prime).unwrap()),
+            )
+            .unwrap();
+
+            assert_eq!(point.order(), Ok(order), "({}, {})", x, y);
+            assert!(point.scalar_mul(order).is_infinity());
+        }
+
+        assert_eq!(Point::infinity(a, b).order(), Ok(1));
+    }
+
+    #[test]
+    fn test_point_order_vertical_tangent() {
+        let x = Fp::new(0, ORDER).unwrap();
+        let y = Fp::new(0, ORDER).unwrap();
+        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+
+        assert_eq!(point_a.order(), Ok(2));
+    }
+
+    #[test]
+    fn test_point_order_off_curve() {
+        // (1, 1) is not on y² = x³ + 7 over 𝔽223: its multiples fall off the curve
+        // instead of cycling back to P(∞)
+        let prime: u32 = 223;
+        let off_curve = Point {
+            a: Fp::new(0, prime).unwrap(),
+            b: Fp::new(7, prime).unwrap(),
+            x: Some(Fp::new(1, prime).unwrap()),
+            y: Some(Fp::new(1, prime).unwrap()),
+        };
+
+        assert!(off_curve.order().is_err());
+    }
+
+    #[test]
+    fn test_point_from_x() {
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+
+        for (x, y, y_neg) in [(47, 71, 152), (15, 86, 137), (17, 56, 167)] {
+            let (point_a, point_b) = Point::from_x(Fp::new(x, prime).unwrap(), a, b).unwrap();
+            let mut ys = [point_a.y.unwrap().num, point_b.y.unwrap().num];
+            ys.sort();
+
+            assert_eq!(point_a.x, Some(Fp::new(x, prime).unwrap()));
+            assert_eq!(point_b.x, Some(Fp::new(x, prime).unwrap()));
+            assert_eq!(ys, [y, y_neg]);
+            assert_eq!(point_a, -point_b);
+        }
+    }
+
+    #[test]
+    fn test_point_from_x_error() {
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let x = Fp::new(4, prime).unwrap();
+
+        assert_eq!(
+            Point::from_x(x, a, b),
+            Err(CryptoError::NotXCoordinate { x: x.to_string() })
+        );
+    }
+
+    #[test]
+    fn test_point_to_sec() {
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+
+        // (47, 71): x = 0x2f, y = 0x47 (odd); (15, 86): x = 0x0f, y = 0x56 (even)
+        let point_a = Point::new(
+            a,
+            b,
+            Some(Fp::new(47, prime).unwrap()),
+            Some(Fp::new(71, prime).unwrap()),
+        )
+        .unwrap();
+        let point_b = Point::new(
+            a,
+            b,
+            Some(Fp::new(15, prime).unwrap()),
+            Some(Fp::new(86, prime).unwrap()),
+        )
+        .unwrap();
+
+        assert_eq!(point_a.to_sec(false), vec![0x04, 0x2f, 0x47]);
+        assert_eq!(point_a.to_sec(true), vec![0x03, 0x2f]);
+        assert_eq!(point_b.to_sec(false), vec![0x04, 0x0f, 0x56]);
+        assert_eq!(point_b.to_sec(true), vec![0x02, 0x0f]);
+        assert_eq!(Point::infinity(a, b).to_sec(true), vec![0x00]);
+    }
+
+    #[test]
+    fn test_point_sec_round_trip() {
+        // 2¹⁶ - 15 is prime, so coordinates take two bytes
+        let prime: u32 = 65521;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+
+        let mut points = vec![Point::infinity(a, b)];
+        for x in (1..prime as i64).step_by(997) {
+            if let Ok((point, negated)) = Point::from_x(Fp::new(x, prime).unwrap(), a, b) {
+                points.push(point);
+                points.push(negated);
+            }
+        }
+        assert!(points.len() > 20);
+
+        for point in points {
+            for compressed in [true, false] {
+                let sec = point.to_sec(compressed);
+
+                assert_eq!(Point::parse_sec(&sec, a, b), Ok(point), "{:02x?}", sec);
+            }
+        }
+    }
+
+    #[test]
+    fn test_point_parse_sec_errors() {
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+
+        assert_eq!(
+            Point::parse_sec(&[0x05, 0x2f, 0x47], a, b),
+            Err(CryptoError::InvalidSecPrefix(0x05))
+        );
+        assert_eq!(Point::parse_sec(&[], a, b), Err(CryptoError::EmptySec));
+        assert_eq!(
+            Point::parse_sec(&[0x04, 0x2f], a, b),
+            Err(CryptoError::InvalidSecLength {
+                length: 2,
+                prefix: 0x04,
+                expected: 3
+            })
+        );
+        assert_eq!(
+            Point::parse_sec(&[0x04, 0x2f, 0x48], a, b),
+            Err(CryptoError::NotOnCurve {
+                x: Fp::new(47, prime).unwrap().to_string(),
+                y: Fp::new(72, prime).unwrap().to_string(),
+                lhs: Fp::new(55, prime).unwrap().to_string(),
+                rhs: Fp::new(135, prime).unwrap().to_string()
+            })
+        );
+        assert_eq!(
+            Point::parse_sec(&[0x02, 0xff], a, b),
+            Err(CryptoError::InvalidSecCoordinate(Box::new(
+                CryptoError::NotInRange {
+                    num: "255".to_string(),
+                    prime: "223".to_string()
+                }
+            )))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn test_scalar_multiplication_big_matches_repeated_addition() {
+        use num_bigint::BigUint;
+
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let generator = Point::new(
+            a,
+            b,
+            Some(Fp::new(15, prime).unwrap()),
+            Some(Fp::new(86, prime).unwrap()),
+        )
+        .unwrap();
+
+        let mut expected = Point::infinity(a, b);
+
+        for by in 0_u32..=30 {
+            assert_eq!(
+                generator.scalar_mul_big(&BigUint::from(by)),
+                expected,
+                "scalar: {}",
+                by
+            );
+            expected = (expected + generator).unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn test_scalar_multiplication_big_200_bit() {
+        use num_bigint::BigUint;
+
+        // (47, 71) has order 21 on y² = x³ + 7 over 𝔽223, so k·G = (k mod 21)·G
+        let prime: u32 = 223;
+        let generator = Point::new(
+            Fp::new(0, prime).unwrap(),
+            Fp::new(7, prime).unwrap(),
+            Some(Fp::new(47, prime).unwrap()),
+            Some(Fp::new(71, prime).unwrap()),
+        )
+        .unwrap();
+
+        let scalar: BigUint = (BigUint::from(1_u32) << 199_u32) + BigUint::from(0xdead_beef_u32);
+        assert_eq!(scalar.bits(), 200);
+
+        let reduced = u32::try_from(&scalar % BigUint::from(21_u32)).unwrap();
+        assert_eq!(
+            generator.scalar_mul_big(&scalar),
+            generator.scalar_mul(reduced)
+        );
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let g = Point::new(
+            a,
+            b,
+            Some(Fp::new(47, prime).unwrap()),
+            Some(Fp::new(71, prime).unwrap()),
+        )
+        .unwrap();
+        let p = Point::new(
+            a,
+            b,
+            Some(Fp::new(17, prime).unwrap()),
+            Some(Fp::new(56, prime).unwrap()),
+        )
+        .unwrap();
+
+        for u in [0_u32, 1, 2, 5, 13, 21, 40] {
+            for v in [0_u32, 1, 3, 7, 8, 42, 100] {
+                assert_eq!(
+                    Point::mul_add(g, u, p, v),
+                    g.scalar_mul(u) + p.scalar_mul(v),
+                    "u: {}, v: {}",
+                    u,
+                    v
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_add_different_curves() {
+        let prime: u32 = 223;
+        let g = Point::new(
+            Fp::new(0, prime).unwrap(),
+            Fp::new(7, prime).unwrap(),
+            Some(Fp::new(47, prime).unwrap()),
+            Some(Fp::new(71, prime).unwrap()),
+        )
+        .unwrap();
+        let p = Point::infinity(Fp::new(5, prime).unwrap(), Fp::new(7, prime).unwrap());
+
+        assert_eq!(
+            Point::mul_add(g, 1, p, 1),
+            Err(CryptoError::DifferentCurves)
+        );
+    }
+
+    #[test]
+    fn test_precomputed_point_matches_scalar_mul() {
+        let prime: u32 = 223;
+        let base = Point::new(
+            Fp::new(0, prime).unwrap(),
+            Fp::new(7, prime).unwrap(),
+            Some(Fp::new(17, prime).unwrap()),
+            Some(Fp::new(56, prime).unwrap()),
+        )
+        .unwrap();
+
+        for window in [2_u8, 3, 4] {
+            let precomputed = PrecomputedPoint::new(base, window).unwrap();
+
+            for scalar in (0_u32..200).chain([1 << 31, u32::MAX - 1, u32::MAX]) {
+                assert_eq!(
+                    precomputed.multiply(scalar),
+                    base.scalar_mul(scalar),
+                    "window: {}, scalar: {}",
+                    window,
+                    scalar
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_precomputed_point_invalid_window() {
+        let base = Point::infinity(SECP256K1_A, SECP256K1_B);
+
+        assert_eq!(
+            PrecomputedPoint::new(base, 0),
+            Err(CryptoError::InvalidWindow(0))
+        );
+        assert_eq!(
+            PrecomputedPoint::new(base, 9),
+            Err(CryptoError::InvalidWindow(9))
+        );
+    }
+
+    #[test]
+    fn test_point_mul_operator() {
+        let prime: u32 = 223;
+        let point = Point::new(
+            Fp::new(0, prime).unwrap(),
+            Fp::new(7, prime).unwrap(),
+            Some(Fp::new(47, prime).unwrap()),
+            Some(Fp::new(71, prime).unwrap()),
+        )
+        .unwrap();
+
+        assert_eq!(3 * point, point.scalar_mul(3));
+        assert_eq!(point * 3, point.scalar_mul(3));
+        assert_eq!(21 * point, Point::infinity(point.a, point.b));
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn test_is_in_subgroup() {
+        use num_bigint::BigUint;
+
+        // #E(𝔽223) = 252 for y² = x³ + 7, (17, 56) has order 42 and (47, 71) order 21
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let point = |x: i64, y: i64| {
+            Point::new(
+                a,
+                b,
+                Some(Fp::new(x, prime).unwrap()),
+                Some(Fp::new(y, prime).unwrap()),
+            )
+            .unwrap()
+        };
+
+        assert!(point(47, 71).is_in_subgroup(&BigUint::from(21_u32)));
+        assert!(!point(17, 56).is_in_subgroup(&BigUint::from(21_u32)));
+        assert!(Point::infinity(a, b).is_in_subgroup(&BigUint::from(21_u32)));
+    }
+
+    #[test]
+    fn test_error_variants() {
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let x = Fp::new(47, prime).unwrap();
+        let y = Fp::new(72, prime).unwrap();
+
+        match Point::new(a, b, Some(x), Some(y)) {
+            Err(CryptoError::NotOnCurve { x, y, lhs, rhs }) => {
+                assert_eq!((x.as_str(), y.as_str()), ("47 — 𝔽223", "72 — 𝔽223"));
+                // (47, 71) is on the curve: 71² = 135, one off in y gives 72² = 55
+                assert_eq!((lhs.as_str(), rhs.as_str()), ("55 — 𝔽223", "135 — 𝔽223"));
+            }
+            other => panic!("expected NotOnCurve, got {:?}", other),
+        }
+
+        let other_curve = Point::infinity(Fp::new(5, prime).unwrap(), b);
+        assert!(matches!(
+            Point::infinity(a, b) + other_curve,
+            Err(CryptoError::DifferentCurves)
+        ));
+        assert!(matches!(
+            a.safe_div(Fp::new(0, prime).unwrap()),
+            Err(CryptoError::DivByZero)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let point = Point::new(
+            a,
+            b,
+            Some(Fp::new(47, prime).unwrap()),
+            Some(Fp::new(71, prime).unwrap()),
+        )
+        .unwrap();
+
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(
+            json,
+            r#"{"a":{"num":0,"modulus":223},"b":{"num":7,"modulus":223},"sec":"042f47"}"#
+        );
+        assert_eq!(serde_json::from_str::<Point<Fp>>(&json).unwrap(), point);
+
+        // y = 72 instead of 71
+        let tampered = json.replace("042f47", "042f48");
+        let error = serde_json::from_str::<Point<Fp>>(&tampered).unwrap_err();
+        assert!(error.to_string().contains("is not on the curve"));
+
+        let infinity = Point::infinity(a, b);
+        let json = serde_json::to_string(&infinity).unwrap();
+        assert_eq!(serde_json::from_str::<Point<Fp>>(&json).unwrap(), infinity);
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn test_weierstrass_curve() {
+        use num_bigint::BigUint;
+
+        // y² = x³ + 7 over 𝔽223, (47, 71) has order 21
+        struct Curve223;
+        // y² = x³ - x - 1 over 𝔽157, (39, 54) has order 79
+        struct Curve157;
 
-        let x2 = Fp::new(4, ORDER).unwrap();
-        let y2 = Fp::new(1, ORDER).unwrap();
-        let point_b = Point::new(SECP256K1_A, SECP256K1_B, Some(x2), Some(y2)).unwrap();
+        impl WeierstrassCurve for Curve223 {
+            type Field = Fp;
+
+            fn a() -> Fp {
+                Fp::new(0, 223).unwrap()
+            }
+            fn b() -> Fp {
+                Fp::new(7, 223).unwrap()
+            }
+            fn field_prime() -> BigUint {
+                BigUint::from(223_u32)
+            }
+            fn generator() -> Point<Fp> {
+                Self::point(Fp::new(47, 223).unwrap(), Fp::new(71, 223).unwrap()).unwrap()
+            }
+            fn order() -> BigUint {
+                BigUint::from(21_u32)
+            }
+        }
+
+        impl WeierstrassCurve for Curve157 {
+            type Field = Fp;
+
+            fn a() -> Fp {
+                Fp::new(-1, 157).unwrap()
+            }
+            fn b() -> Fp {
+                Fp::new(-1, 157).unwrap()
+            }
+            fn field_prime() -> BigUint {
+                BigUint::from(157_u32)
+            }
+            fn generator() -> Point<Fp> {
+                Self::point(Fp::new(39, 157).unwrap(), Fp::new(54, 157).unwrap()).unwrap()
+            }
+            fn order() -> BigUint {
+                BigUint::from(79_u32)
+            }
+        }
+
+        let g = Curve223::generator();
+        let h = Curve157::generator();
+
+        assert_eq!(g.scalar_mul_big(&Curve223::order()), Curve223::infinity());
+        assert_eq!(h.scalar_mul_big(&Curve157::order()), Curve157::infinity());
+        assert!(Curve223::contains(&g) && !Curve223::contains(&h));
+        assert_eq!(g + h, Err(CryptoError::DifferentCurves));
+        assert!(matches!(
+            Curve223::point(Fp::new(4, 223).unwrap(), Fp::new(1, 223).unwrap()),
+            Err(CryptoError::NotOnCurve { .. })
+        ));
+    }
 
+    #[test]
+    fn test_subgroup() {
+        // y² = x³ + 7 over 𝔽223, (15, 86) has order 7
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let generator = Point::new(
+            a,
+            b,
+            Some(Fp::new(15, prime).unwrap()),
+            Some(Fp::new(86, prime).unwrap()),
+        )
+        .unwrap();
+
+        let subgroup: Vec<Point<Fp>> = generator.subgroup().collect();
+        let coordinates: Vec<Option<(u32, u32)>> = subgroup
+            .iter()
+            .map(|point| point.x.zip(point.y).map(|(x, y)| (x.num, y.num)))
+            .collect();
+
+        assert_eq!(subgroup.len() as u32, generator.order().unwrap());
         assert_eq!(
-            point_infinity + point_a,
-            Ok(Point {
-                a: SECP256K1_A,
-                b: SECP256K1_B,
-                x: Some(Fp::new(1, ORDER).unwrap()),
-                y: Some(Fp::new(1, ORDER).unwrap()),
-            })
+            coordinates,
+            vec![
+                Some((15, 86)),
+                Some((139, 86)),
+                Some((69, 137)),
+                Some((69, 86)),
+                Some((139, 137)),
+                Some((15, 137)),
+                None,
+            ]
         );
 
+        let identity = Point::infinity(a, b);
+        assert_eq!(identity.subgroup().collect::<Vec<_>>(), vec![identity]);
+    }
+
+    #[test]
+    fn test_all_points() {
+        let points = Point::all_points(SECP256K1_A, SECP256K1_B).unwrap();
+        let coordinates: Vec<Option<(u32, u32)>> = points
+            .iter()
+            .map(|point| point.x.zip(point.y).map(|(x, y)| (x.num, y.num)))
+            .collect();
+
         assert_eq!(
-            point_b + point_infinity,
-            Ok(Point {
-                a: SECP256K1_A,
-                b: SECP256K1_B,
-                x: Some(Fp::new(4, ORDER).unwrap()),
-                y: Some(Fp::new(6, ORDER).unwrap()),
+            coordinates,
+            vec![
+                None,
+                Some((0, 0)),
+                Some((1, 1)),
+                Some((1, 6)),
+                Some((2, 1)),
+                Some((2, 6)),
+                Some((4, 1)),
+                Some((4, 6)),
+            ]
+        );
+
+        // b = 7 is 0 in 𝔽7, y² = x³ is singular and those 8 points are not a group. Over
+        // 𝔽11 it is a curve, and every point's order divides the 12 points found
+        let (a, b) = (Fp::new(0, ORDER_2).unwrap(), Fp::new(7, ORDER_2).unwrap());
+        let points = Point::all_points(a, b).unwrap();
+
+        assert_eq!(points.len(), 12);
+        for point in &points {
+            assert_eq!(12 % point.order().unwrap(), 0);
+        }
+
+        assert_eq!(
+            Point::all_points(SECP256K1_A, Fp::new(7, ORDER_2).unwrap()),
+            Err(CryptoError::DifferentFields {
+                operation: "define a curve with"
             })
         );
     }
 
     #[test]
-    fn test_point_addition_identity_variation() {
-        let x1 = Fp::new(1, ORDER).unwrap();
-        let y1 = Fp::new(6, ORDER).unwrap();
-        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x1), Some(y1)).unwrap();
+    fn test_curve_order() {
+        // y² = x³ + 7, counted by hand for 𝔽7 (singular there, see `test_all_points`) and 𝔽11
+        for (prime, expected) in [(7, 8), (11, 12), (223, 252)] {
+            let a = Fp::new(0, prime).unwrap();
+            let b = Fp::new(7, prime).unwrap();
+            let order = Point::curve_order(a, b).unwrap();
 
-        let x2 = Fp::new(1, ORDER).unwrap();
-        let y2 = Fp::new(1, ORDER).unwrap();
-        let point_b = Point::new(SECP256K1_A, SECP256K1_B, Some(x2), Some(y2)).unwrap();
+            // Hasse: |#E - (p + 1)| ≤ 2√p
+            let distance = (order as f64 - (prime as f64 + 1.0)).abs();
+            assert!(distance <= 2.0 * (prime as f64).sqrt());
+            assert_eq!(order, expected);
+        }
+
+        // y² = x³ - x - 1 over 𝔽157, the curve of the (39, 54) generator of order 79
+        let a = Fp::new(-1, 157).unwrap();
+        assert_eq!(Point::curve_order(a, a), Ok(158));
+    }
+
+    #[test]
+    fn test_scalar_mul_matches_affine() {
+        // y² = x³ + 7 over 𝔽223, (17, 56) has order 42 so 0..=40 covers every multiple
+        // but one, including the doublings that land on y = 0 (21·P)
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let point = Point::new(
+            a,
+            b,
+            Some(Fp::new(17, prime).unwrap()),
+            Some(Fp::new(56, prime).unwrap()),
+        )
+        .unwrap();
 
+        let mut affine = Point::infinity(a, b);
+        for scalar in 0..=40 {
+            assert_eq!(point.scalar_mul(scalar), affine);
+            affine = (affine + point).unwrap();
+        }
+
+        // a ≠ 0: y² = x³ - x - 1 over 𝔽157, (39, 54) has order 79
+        let a = Fp::new(-1, 157).unwrap();
+        let point = Point::new(
+            a,
+            a,
+            Some(Fp::new(39, 157).unwrap()),
+            Some(Fp::new(54, 157).unwrap()),
+        )
+        .unwrap();
+
+        let mut affine = Point::infinity(a, a);
+        for scalar in 0..=80 {
+            assert_eq!(point.scalar_mul(scalar), affine);
+            affine = (affine + point).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_sum_points() {
+        // the 42 multiples of (17, 56) on y² = x³ + 7 over 𝔽223, P(∞) included
+        let prime: u32 = 223;
+        let a = Fp::new(0, prime).unwrap();
+        let b = Fp::new(7, prime).unwrap();
+        let generator = Point::new(
+            a,
+            b,
+            Some(Fp::new(17, prime).unwrap()),
+            Some(Fp::new(56, prime).unwrap()),
+        )
+        .unwrap();
+        let multiples: Vec<Point<Fp>> = generator.subgroup().collect();
+
+        // xorshift64 picks, so lists mix repeats, opposite points and P(∞)
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for length in 1..=40 {
+            let points: Vec<Point<Fp>> = (0..length)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    multiples[(state % multiples.len() as u64) as usize]
+                })
+                .collect();
+
+            let naive = points[1..]
+                .iter()
+                .fold(points[0], |sum, &point| (sum + point).unwrap());
+            assert_eq!(Point::sum_points(&points), Ok(naive));
+        }
+
+        let pairs = [
+            generator,
+            -generator,
+            generator.scalar_mul(21),
+            generator.scalar_mul(21),
+        ];
+        assert_eq!(Point::sum_points(&pairs), Ok(Point::infinity(a, b)));
+    }
+
+    #[test]
+    fn test_sum_points_errors() {
+        let point = Point::new(
+            Fp::new(0, 223).unwrap(),
+            Fp::new(7, 223).unwrap(),
+            Some(Fp::new(47, 223).unwrap()),
+            Some(Fp::new(71, 223).unwrap()),
+        )
+        .unwrap();
+        let other = Point::infinity(Fp::new(5, 223).unwrap(), Fp::new(7, 223).unwrap());
+
+        assert_eq!(Point::<Fp>::sum_points(&[]), Err(CryptoError::EmptySum));
         assert_eq!(
-            point_a + point_b,
-            Ok(Point {
-                a: SECP256K1_A,
-                b: SECP256K1_B,
-                x: None,
-                y: None,
-            })
+            Point::sum_points(&[point, other]),
+            Err(CryptoError::DifferentCurves)
         );
     }
 
     #[test]
-    fn test_scalar_multiplication() {
-        let x = Fp::new(1, ORDER).unwrap();
-        let y = Fp::new(6, ORDER).unwrap();
-        let point_a = Point::new(SECP256K1_A, SECP256K1_B, Some(x), Some(y)).unwrap();
+    fn test_curve_b_for() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+
+        // (47, 71) is on y² = x³ + 7
+        assert_eq!(curve_b_for(fe(0), fe(47), fe(71)), fe(7));
+
+        for (a, x, y) in [(5, 3, 10), (-1, 0, 0), (100, 222, 1)] {
+            let b = curve_b_for(fe(a), fe(x), fe(y));
+
+            assert!(Point::new(fe(a), b, Some(fe(x)), Some(fe(y))).is_ok());
+            assert!(Point::new(fe(a), b + fe(1), Some(fe(x)), Some(fe(y))).is_err());
+        }
+    }
+
+    #[test]
+    fn test_default_element() {
+        for num in [0, 1, 100, 222] {
+            let element = Fp::new(num, 223).unwrap();
+            let default = element.default_element();
+
+            assert_eq!(default.modulus, 223);
+            assert_eq!(element + default, element);
+            assert_eq!(default, element.zero());
+        }
+    }
+
+    #[test]
+    fn test_scalar_mul_bits() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let point = Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71))).unwrap();
+        let to_bits =
+            |n: u32, width: u32| (0..width).map(|i| (n >> i) & 1 == 1).collect::<Vec<_>>();
+
+        for n in 0..50 {
+            assert_eq!(point.scalar_mul_bits(&to_bits(n, 6)), point.scalar_mul(n));
+            assert_eq!(point.scalar_mul_bits(&to_bits(n, 32)), point.scalar_mul(n));
+        }
 
+        // [1, 1, 0] little-endian is 3, not 6
         assert_eq!(
-            point_a.scalar_mul(5),
-            Point {
-                a: SECP256K1_A,
-                b: SECP256K1_B,
-                x: Some(Fp::new(2, ORDER).unwrap()),
-                y: Some(Fp::new(1, ORDER).unwrap()),
+            point.scalar_mul_bits(&[true, true, false]),
+            point.scalar_mul(3)
+        );
+        assert!(point.scalar_mul_bits(&[]).is_infinity());
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_scalar_mul_blinded() {
+        use num_bigint::BigUint;
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+
+        // (47, 71) has order 21
+        let point = Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71))).unwrap();
+        let n = BigUint::from(21_u32);
+
+        for k in 0..42_u32 {
+            for _ in 0..20 {
+                assert_eq!(
+                    point.scalar_mul_blinded(&BigUint::from(k), &n, &mut rng),
+                    point.scalar_mul(k)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_wnaf_digits() {
+        assert_eq!(wnaf(7, 2), Ok(vec![-1, 0, 0, 1]));
+        assert_eq!(wnaf(0, 4), Ok(Vec::new()));
+
+        for width in 2..=8_u8 {
+            for scalar in (0..2000).chain([u32::MAX - 1, u32::MAX]) {
+                let digits = wnaf(scalar, width).unwrap();
+                let value = digits
+                    .iter()
+                    .rev()
+                    .fold(0_i64, |value, &digit| 2 * value + digit as i64);
+
+                assert_eq!(value, scalar as i64);
+                assert!(digits.iter().all(
+                    |&d| d == 0 || (d % 2 != 0 && (d.unsigned_abs() as u32) < 1 << (width - 1))
+                ));
+                for window in digits.windows(width as usize) {
+                    assert!(window.iter().filter(|&&d| d != 0).count() <= 1);
+                }
             }
+        }
+    }
+
+    #[test]
+    fn test_wnaf_invalid_width() {
+        for width in [0, 1, 9, 63, 64, u8::MAX] {
+            assert_eq!(wnaf(7, width), Err(CryptoError::InvalidNafWidth(width)));
+        }
+    }
+
+    #[test]
+    fn test_scalar_mul_wnaf() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+
+        // (17, 56) has order 42
+        let point = Point::new(fe(0), fe(7), Some(fe(17)), Some(fe(56))).unwrap();
+
+        for width in 2..=5 {
+            for scalar in 0..=100 {
+                assert_eq!(
+                    point.scalar_mul_wnaf(scalar, width),
+                    Ok(point.scalar_mul(scalar))
+                );
+            }
+        }
+
+        assert_eq!(
+            point.scalar_mul_wnaf(u32::MAX, 8),
+            Ok(point.scalar_mul(u32::MAX))
+        );
+        assert!(Point::infinity(fe(0), fe(7))
+            .scalar_mul_wnaf(5, 3)
+            .unwrap()
+            .is_infinity());
+        assert_eq!(
+            point.scalar_mul_wnaf(5, 1),
+            Err(CryptoError::InvalidNafWidth(1))
+        );
+        assert_eq!(
+            point.scalar_mul_wnaf(5, 9),
+            Err(CryptoError::InvalidNafWidth(9))
+        );
+    }
+
+    #[test]
+    fn test_half() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+
+        // orders 21 and 7
+        for (x, y, order) in [(47, 71, 21), (15, 86, 7)] {
+            let point = Point::new(fe(0), fe(7), Some(fe(x)), Some(fe(y))).unwrap();
+
+            for multiple in point.subgroup() {
+                let half = multiple.half(order).unwrap();
+
+                assert_eq!(half.double(), Ok(multiple));
+            }
+        }
+
+        // (17, 56) has order 42
+        let point = Point::new(fe(0), fe(7), Some(fe(17)), Some(fe(56))).unwrap();
+        assert_eq!(point.half(42), Err(CryptoError::EvenOrder(42)));
+        assert!(Point::infinity(fe(0), fe(7))
+            .half(21)
+            .unwrap()
+            .is_infinity());
+    }
+
+    #[test]
+    fn test_accessors() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let point = Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71))).unwrap();
+
+        assert_eq!(point.coordinates(), Some((fe(47), fe(71))));
+        assert_eq!((point.a(), point.b()), (fe(0), fe(7)));
+        assert_eq!(point.scalar_mul(2).coordinates(), Some((fe(36), fe(111))));
+
+        let infinity = point.scalar_mul(21);
+        assert_eq!(infinity.coordinates(), None);
+        assert_eq!((infinity.a(), infinity.b()), (fe(0), fe(7)));
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        let prime = 223;
+        let (a, b) = (Fp::new(0, prime).unwrap(), Fp::new(7, prime).unwrap());
+
+        let point = hash_to_curve(b"commitment", a, b);
+        assert_eq!(hash_to_curve(b"commitment", a, b), point);
+
+        let (x, y) = point.coordinates().unwrap();
+        assert!(Point::is_on_curve(&x, &y, &a, &b));
+        assert!(!y.is_odd());
+
+        // 𝔽223 only has 252 points, but a handful of messages still spread out
+        let points: Vec<_> = (0..8_u8).map(|i| hash_to_curve(&[i], a, b)).collect();
+        assert!(points.iter().skip(1).any(|other| *other != points[0]));
+    }
+
+    #[test]
+    fn test_pedersen_commit() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let g = Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71))).unwrap();
+        let h = hash_to_curve(b"H", fe(0), fe(7));
+        let commit = |value, blinding| pedersen_commit(g, h, value, blinding).unwrap();
+
+        for (v1, b1, v2, b2) in [(1, 2, 3, 4), (0, 0, 5, 9), (20, 17, 30, 100), (7, 1, 14, 1)] {
+            assert_eq!(
+                (commit(v1, b1) + commit(v2, b2)).unwrap(),
+                commit(v1 + v2, b1 + b2)
+            );
+        }
+
+        assert_eq!(commit(5, 0), g.scalar_mul(5));
+        assert_eq!(commit(0, 5), h.scalar_mul(5));
+
+        let other_curve = Point::infinity(fe(1), fe(7));
+        assert_eq!(
+            pedersen_commit(g, other_curve, 1, 1),
+            Err(CryptoError::DifferentCurves)
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let p = Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71))).unwrap();
+        let q = Point::new(fe(0), fe(7), Some(fe(15)), Some(fe(86))).unwrap();
+        let infinity = Point::infinity(fe(0), fe(7));
+
+        for (a, b) in [(p, q), (p, infinity), (infinity, q), (infinity, infinity)] {
+            assert_eq!(Point::select(&a, &b, false), a);
+            assert_eq!(Point::select(&a, &b, true), b);
+        }
+    }
+
+    #[test]
+    fn test_same_point() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let p = Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71))).unwrap();
+        let infinity = Point::infinity(fe(0), fe(7));
+
+        assert!(p.same_point(&p));
+        assert!(infinity.same_point(&Point::infinity(fe(0), fe(7))));
+        assert!(!p.same_point(&infinity));
+        assert!(!infinity.same_point(&p));
+        assert!(!p.same_point(&p.double().unwrap()));
+
+        // (47, 71) on another curve: the same coordinates, but not `==`
+        let b = curve_b_for(fe(1), fe(47), fe(71));
+        let q = Point::new(fe(1), b, Some(fe(47)), Some(fe(71))).unwrap();
+        assert!(p.same_point(&q));
+        assert_ne!(p, q);
+
+        // with no coordinates only the curve is left to tell them apart, as for `==`
+        let other_infinity = Point::infinity(fe(1), b);
+        assert!(!infinity.same_point(&other_infinity));
+        assert_ne!(infinity, other_infinity);
+    }
+
+    #[test]
+    fn test_is_non_singular() {
+        let fe = |num| Fp::new(num, 223).unwrap();
+
+        // secp256k1's (a, b), here over 𝔽223
+        assert!(is_non_singular(fe(0), fe(7)));
+        // y² = x³ (cusp) and y² = x³ - 3x + 2 = (x - 1)²(x + 2) (node)
+        assert!(!is_non_singular(fe(0), fe(0)));
+        assert!(!is_non_singular(-fe(3), fe(2)));
+    }
+
+    #[test]
+    fn test_point_new_checked() {
+        let fe = |num| Fp::new(num, 223).unwrap();
+
+        assert_eq!(
+            Point::new_checked(fe(0), fe(7), Some(fe(47)), Some(fe(71))),
+            Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71)))
+        );
+
+        // (1, 0) is on y² = x³ - 3x + 2, so `new` takes it
+        let (a, b) = (-fe(3), fe(2));
+        assert!(Point::new(a, b, Some(fe(1)), Some(fe(0))).is_ok());
+
+        let singular = Err(CryptoError::SingularCurve {
+            a: "220 — 𝔽223".to_string(),
+            b: "2 — 𝔽223".to_string(),
+        });
+        assert_eq!(Point::new_checked(a, b, Some(fe(1)), Some(fe(0))), singular);
+        assert_eq!(Point::new_checked(a, b, None, None), singular);
+    }
+
+    #[test]
+    fn test_mul_cache() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let base = Point::new(fe(0), fe(7), Some(fe(17)), Some(fe(56))).unwrap();
+        let mut cache = base.mul_cache();
+
+        assert!(cache.is_empty());
+
+        for scalar in (0_u32..100).chain([1 << 31, u32::MAX]) {
+            assert_eq!(cache.multiply(scalar), base.scalar_mul(scalar));
+        }
+        assert_eq!(cache.len(), 102);
+
+        // answered again from the cache, without growing it
+        for scalar in [0, 42, 99, u32::MAX] {
+            assert_eq!(cache.multiply(scalar), base.scalar_mul(scalar));
+        }
+        assert_eq!(cache.len(), 102);
+    }
+
+    #[test]
+    fn test_mul_cache_grows_lazily() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let base = Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71))).unwrap();
+        let mut cache = base.mul_cache();
+
+        assert_eq!((cache.len(), cache.doublings.len()), (0, 0));
+
+        // 5 = 0b101 needs base, 2·base and 4·base
+        assert_eq!(cache.multiply(5), base.scalar_mul(5));
+        assert_eq!((cache.len(), cache.doublings.len()), (1, 3));
+
+        // 3 = 0b11 needs no new doublings
+        assert_eq!(cache.multiply(3), base.scalar_mul(3));
+        assert_eq!((cache.len(), cache.doublings.len()), (2, 3));
+
+        assert_eq!(cache.multiply(40), base.scalar_mul(40));
+        assert_eq!((cache.len(), cache.doublings.len()), (3, 6));
+    }
+
+    #[test]
+    fn test_point_addition_mixed_fields() {
+        let fe = |num, prime| Fp::new(num, prime).unwrap();
+        let p = Point::new(fe(0, 223), fe(7, 223), Some(fe(47, 223)), Some(fe(71, 223))).unwrap();
+
+        // the same-looking numbers, but the coordinates are in 𝔽227
+        let mixed = Point {
+            x: Some(fe(47, 227)),
+            y: Some(fe(71, 227)),
+            ..p
+        };
+        assert_eq!(p + mixed, Err(CryptoError::MixedFields));
+        assert_eq!(mixed + p, Err(CryptoError::MixedFields));
+
+        // a consistent point over 𝔽227 is simply on another curve
+        let other = Point::infinity(fe(0, 227), fe(7, 227));
+        assert_eq!(p + other, Err(CryptoError::DifferentCurves));
+
+        // P(∞) with its curve in one field and nothing else to check
+        let infinity = Point::infinity(fe(0, 223), fe(7, 227));
+        assert_eq!(infinity + infinity, Err(CryptoError::MixedFields));
+
+        assert_eq!(
+            CryptoError::MixedFields.to_string(),
+            "points span different fields"
         );
     }
+
+    #[test]
+    fn test_scalar_mul_steps() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let point = Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71))).unwrap();
+        let infinity = Point::infinity(fe(0), fe(7));
+
+        // 13 = 0b1101: ∞, P, 2P, 3P, 6P, 12P, 13P
+        let expected: Vec<_> = [0, 1, 2, 3, 6, 12, 13]
+            .iter()
+            .map(|&k| point.scalar_mul(k))
+            .collect();
+        assert_eq!(point.scalar_mul_steps(13), expected);
+
+        for by in (1..100).chain([u32::MAX]) {
+            let steps = point.scalar_mul_steps(by);
+            let bits = u32::BITS - by.leading_zeros();
+
+            assert_eq!(steps.len() as u32, bits + by.count_ones());
+            assert_eq!(steps.last(), Some(&point.scalar_mul(by)));
+        }
+
+        assert!(point.scalar_mul_steps(0).is_empty());
+        assert!(infinity.scalar_mul_steps(5).iter().all(|p| p.is_infinity()));
+    }
+
+    #[test]
+    fn test_two_torsion_scalar_multiplication() {
+        // (0, 0) on y² = x³ + x over 𝔽223: y = 0, so 2P = P(∞), 3P = P, ...
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let (a, b) = (fe(1), fe(0));
+        let point = Point::new(a, b, Some(fe(0)), Some(fe(0))).unwrap();
+        let infinity = Point::infinity(a, b);
+
+        assert_eq!(point.order(), Ok(2));
+        assert_eq!(-point, point);
+        assert_eq!(point - point, Ok(infinity));
+
+        let precomputed = PrecomputedPoint::new(point, 2).unwrap();
+        let mut cache = point.mul_cache();
+
+        for k in 0_u32..=5 {
+            let expected = if k % 2 == 0 { infinity } else { point };
+
+            assert_eq!(point.scalar_mul(k), expected, "scalar_mul({})", k);
+            assert_eq!(
+                point.scalar_mul_ladder(k),
+                expected,
+                "scalar_mul_ladder({})",
+                k
+            );
+            assert_eq!(point.scalar_mul_wnaf(k, 3), Ok(expected), "wnaf({})", k);
+            assert_eq!(precomputed.multiply(k), expected, "precomputed({})", k);
+            assert_eq!(cache.multiply(k), expected, "mul_cache({})", k);
+            assert_eq!(point * k, expected, "point * {}", k);
+            assert_eq!(
+                (0..k).try_fold(infinity, |sum, _| sum + point),
+                Ok(expected),
+                "repeated addition {}",
+                k
+            );
+            assert_eq!(
+                point.scalar_mul_steps(k).last().unwrap_or(&infinity),
+                &expected
+            );
+            assert_eq!(
+                Point::x_only_mul(fe(0), k as u128, a, b),
+                if k % 2 == 0 {
+                    Err(CryptoError::XOnlyInfinity)
+                } else {
+                    Ok(fe(0))
+                },
+                "x_only_mul({})",
+                k
+            );
+            #[cfg(feature = "bigint")]
+            assert_eq!(
+                point.scalar_mul_big(&num_bigint::BigUint::from(k)),
+                expected,
+                "scalar_mul_big({})",
+                k
+            );
+        }
+
+        // with another point: P + Q - P = Q, whatever the order of the steps
+        let q = (2..100)
+            .find_map(|x| Point::from_x(fe(x), a, b).ok())
+            .unwrap()
+            .0;
+        assert_eq!(((point + q).unwrap() + point), Ok(q));
+        assert_eq!(Point::mul_add(point, 3, q, 1), point + q);
+        assert_eq!(Point::mul_add(point, 4, q, 1), Ok(q));
+    }
+
+    #[test]
+    // the references are what is being tested, `Copy` or not
+    #[allow(clippy::op_ref)]
+    fn test_reference_operators() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let p = Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71))).unwrap();
+        let q = Point::new(fe(0), fe(7), Some(fe(15)), Some(fe(86))).unwrap();
+        let other_curve = Point::infinity(fe(1), fe(7));
+
+        assert_eq!(&p + &q, p + q);
+        assert_eq!(&p + &p, p + p);
+        assert_eq!(&p - &q, p - q);
+        assert_eq!(&p - &p, Ok(Point::infinity(fe(0), fe(7))));
+        assert_eq!(&p + &other_curve, Err(CryptoError::DifferentCurves));
+    }
+
+    #[test]
+    fn test_y_is_even() {
+        let prime = 223;
+        let fe = |num| Fp::new(num, prime).unwrap();
+        let (a, b) = (fe(0), fe(7));
+
+        let odd = Point::new(a, b, Some(fe(47)), Some(fe(71))).unwrap();
+        let even = -odd;
+        assert_eq!(even.y, Some(fe(152)));
+
+        assert_eq!(odd.y_is_even(), Some(false));
+        assert_eq!(even.y_is_even(), Some(true));
+        assert_eq!(Point::infinity(a, b).y_is_even(), None);
+
+        // what the compressed SEC prefix carries
+        assert_eq!(odd.to_sec(true)[0], 0x03);
+        assert_eq!(even.to_sec(true)[0], 0x02);
+        for point in [odd, even] {
+            assert_eq!(Point::parse_sec(&point.to_sec(true), a, b), Ok(point));
+        }
+    }
 }
@@ -0,0 +1,1574 @@
+// Book: Programming Bitcoin: Learn How to Program Bitcoin from Scratch by Jimmy song
+// Chapter 3: Elliptic Curve Cryptography — signing and verification
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use hmac::{Hmac, Mac};
+use num_bigint::BigUint;
+use sha2::Sha256;
+
+use crate::exercises::{
+    base58,
+    ec_point::{Point, PrimeField, WeierstrassCurve},
+    hashes::hash256,
+    order::OrderScalar,
+    s256::Secp256k1,
+};
+
+/*
+ * An ECDSA signature, `r` and `s` are scalars modulo the group order n
+ */
+#[derive(PartialEq, Debug, Clone)]
+pub struct Signature {
+    pub r: BigUint,
+    pub s: BigUint,
+}
+
+impl Signature {
+    pub fn new(r: BigUint, s: BigUint) -> Self {
+        Self { r, s }
+    }
+
+    /*
+     * (r, s) and (r, n - s) both verify, so anyone can flip s without the key.
+     * BIP-62 / BIP-146 make s ≤ n/2 the only canonical form
+     */
+    pub fn is_low_s(&self, n: &BigUint) -> bool {
+        self.s <= n / 2_u32
+    }
+
+    // the low-s twin of this signature, itself when it already is low-s
+    pub fn normalize_s(&self, n: &BigUint) -> Self {
+        if self.is_low_s(n) {
+            return self.clone();
+        }
+
+        Self::new(self.r.clone(), n - &self.s)
+    }
+
+    /*
+     * DER: 0x30 <length> 0x02 <length> <r> 0x02 <length> <s>
+     *
+     * `r` and `s` are big-endian without leading zeros, except that a 0x00 is prepended
+     * when the first byte has its high bit set so the INTEGER does not read as negative.
+     * The lengths are short-form, which holds for anything up to 32 bytes; wider r or s
+     * are rejected, as `parse_der` would
+     */
+    pub fn to_der(&self) -> Result<Vec<u8>, String> {
+        self.check_width()?;
+
+        let mut body = der_integer(&self.r);
+        body.extend(der_integer(&self.s));
+
+        let mut der = vec![0x30, body.len() as u8];
+        der.extend(body);
+
+        Ok(der)
+    }
+
+    // `Signature::new` takes any r and s, the encodings only those of up to 32 bytes
+    fn check_width(&self) -> Result<(), String> {
+        for (name, value) in [("r", &self.r), ("s", &self.s)] {
+            if value.bits() > 256 {
+                return Err(format!("{} does not fit in 32 bytes", name));
+            }
+        }
+
+        Ok(())
+    }
+
+    /*
+     * strict (BIP-66) DER: short-form lengths that add up, minimal positive integers that
+     * fit in 32 bytes (33 with the 0x00 sign byte)
+     */
+    pub fn parse_der(bytes: &[u8]) -> Result<Signature, String> {
+        if bytes.len() < 2 {
+            return Err("DER signature is truncated".to_string());
+        }
+
+        if bytes[0] != 0x30 {
+            return Err(format!("invalid DER sequence marker 0x{:02x}", bytes[0]));
+        }
+
+        if bytes[1] as usize != bytes.len() - 2 {
+            return Err(format!(
+                "DER length {} does not match the {} bytes that follow it",
+                bytes[1],
+                bytes.len() - 2
+            ));
+        }
+
+        let mut position = 2;
+        let r = parse_der_integer(bytes, &mut position)?;
+        let s = parse_der_integer(bytes, &mut position)?;
+
+        if position != bytes.len() {
+            return Err(format!(
+                "{} unexpected bytes after the DER signature",
+                bytes.len() - position
+            ));
+        }
+
+        Ok(Signature::new(r, s))
+    }
+
+    /*
+     * DER as it was accepted before BIP-66, for checking old chain data (after
+     * libsecp256k1's lax parser): lengths may use the long form, with any number of leading
+     * zero bytes; integers may have excess leading zeros or a high bit set, and are read as
+     * unsigned; the sequence length is not checked and anything after `s` is ignored.
+     * `r` and `s` still have to fit in 32 bytes
+     */
+    pub fn parse_der_lax(bytes: &[u8]) -> Result<Signature, String> {
+        if bytes.len() < 2 {
+            return Err("DER signature is truncated".to_string());
+        }
+
+        if bytes[0] != 0x30 {
+            return Err(format!("invalid DER sequence marker 0x{:02x}", bytes[0]));
+        }
+
+        let mut position = 1;
+        parse_der_length_lax(bytes, &mut position)?;
+
+        let r = parse_der_integer_lax(bytes, &mut position)?;
+        let s = parse_der_integer_lax(bytes, &mut position)?;
+
+        Ok(Signature::new(r, s))
+    }
+
+    /*
+     * The fixed-width form used by Ethereum, WebCrypto and the like: r || s, each as 32
+     * big-endian bytes. r or s wider than 32 bytes have no compact form
+     */
+    pub fn to_compact(&self) -> Result<[u8; 64], String> {
+        self.check_width()?;
+
+        let mut bytes = [0_u8; 64];
+        bytes[..32].copy_from_slice(&to_32_bytes(&self.r));
+        bytes[32..].copy_from_slice(&to_32_bytes(&self.s));
+
+        Ok(bytes)
+    }
+
+    // the inverse of `to_compact`, both halves have to be in [1, n)
+    pub fn from_compact(bytes: &[u8; 64], n: &BigUint) -> Result<Signature, String> {
+        let r = BigUint::from_bytes_be(&bytes[..32]);
+        let s = BigUint::from_bytes_be(&bytes[32..]);
+
+        for (name, value) in [("r", &r), ("s", &s)] {
+            if *value == BigUint::from(0_u32) || value >= n {
+                return Err(format!("{} must be between 1 and n - 1", name));
+            }
+        }
+
+        Ok(Signature::new(r, s))
+    }
+}
+
+// reads 0x02 <length> <bytes> at `position`, leaving `position` just after it
+fn parse_der_integer(bytes: &[u8], position: &mut usize) -> Result<BigUint, String> {
+    if bytes.len() < *position + 2 {
+        return Err("DER signature is truncated".to_string());
+    }
+
+    if bytes[*position] != 0x02 {
+        return Err(format!(
+            "invalid DER integer marker 0x{:02x}",
+            bytes[*position]
+        ));
+    }
+
+    let length = bytes[*position + 1] as usize;
+    let start = *position + 2;
+
+    if length > 33 {
+        return Err("DER integer does not fit in 32 bytes".to_string());
+    }
+
+    if length == 0 || bytes.len() < start + length {
+        return Err("DER signature is truncated".to_string());
+    }
+
+    if bytes[start] & 0x80 != 0 {
+        return Err("negative DER integer".to_string());
+    }
+
+    if length > 1 && bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0 {
+        return Err("DER integer has excess leading zeros".to_string());
+    }
+
+    let num = BigUint::from_bytes_be(&bytes[start..start + length]);
+    if num.bits() > 256 {
+        return Err("DER integer does not fit in 32 bytes".to_string());
+    }
+
+    *position = start + length;
+
+    Ok(num)
+}
+
+// a short- or long-form length at `position`, leaving `position` just after it
+fn parse_der_length_lax(bytes: &[u8], position: &mut usize) -> Result<usize, String> {
+    let first = *bytes.get(*position).ok_or("DER signature is truncated")?;
+    *position += 1;
+
+    if first & 0x80 == 0 {
+        return Ok(first as usize);
+    }
+
+    // 0x80 | k, then a k-byte big-endian length
+    let count = (first & 0x7f) as usize;
+    let digits = bytes
+        .get(*position..*position + count)
+        .ok_or("DER signature is truncated")?;
+    *position += count;
+
+    let significant: Vec<u8> = digits.iter().copied().skip_while(|&b| b == 0).collect();
+    if significant.len() > std::mem::size_of::<usize>() {
+        return Err("DER length does not fit in memory".to_string());
+    }
+
+    Ok(significant
+        .iter()
+        .fold(0, |length, &b| (length << 8) | b as usize))
+}
+
+fn parse_der_integer_lax(bytes: &[u8], position: &mut usize) -> Result<BigUint, String> {
+    match bytes.get(*position) {
+        Some(0x02) => *position += 1,
+        Some(marker) => return Err(format!("invalid DER integer marker 0x{:02x}", marker)),
+        None => return Err("DER signature is truncated".to_string()),
+    }
+
+    let length = parse_der_length_lax(bytes, position)?;
+    let digits = bytes
+        .get(*position..)
+        .and_then(|rest| rest.get(..length))
+        .ok_or("DER signature is truncated")?;
+    *position += length;
+
+    let num = BigUint::from_bytes_be(digits);
+    if num.bits() > 256 {
+        return Err("DER integer does not fit in 32 bytes".to_string());
+    }
+
+    Ok(num)
+}
+
+fn der_integer(num: &BigUint) -> Vec<u8> {
+    let mut bytes = num.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0x00);
+    }
+
+    let mut integer = vec![0x02, bytes.len() as u8];
+    integer.extend(bytes);
+
+    integer
+}
+
+/*
+ * A secret scalar e in [1, n) and its public key P = e·G on the curve `C`, secp256k1
+ * unless another is named (`PrivateKey::<P256>::from_secret`). The order n has to fit in
+ * 256 bits, as it does for every curve here
+ *
+ * The scalar is kept as its 32 big-endian bytes so the `zeroize` feature can wipe them on
+ * drop. The BigUint copies made while computing with it are not covered
+ */
+#[derive(Debug, Clone)]
+pub struct PrivateKey<C: WeierstrassCurve = Secp256k1> {
+    secret: [u8; 32],
+    curve: PhantomData<C>,
+}
+
+// compares every byte of the secrets, with no early exit at the first one that differs
+impl<C: WeierstrassCurve> PartialEq for PrivateKey<C> {
+    fn eq(&self, other: &Self) -> bool {
+        let difference = self
+            .secret
+            .iter()
+            .zip(&other.secret)
+            .fold(0_u8, |difference, (a, b)| difference | (a ^ b));
+
+        std::hint::black_box(difference) == 0
+    }
+}
+
+impl<C: WeierstrassCurve> PrivateKey<C> {
+    pub fn from_secret(secret: BigUint) -> Result<Self, String> {
+        if secret == BigUint::from(0_u32) || secret >= C::order() {
+            return Err("private key must be between 1 and n - 1".to_string());
+        }
+
+        let mut bytes = [0_u8; 32];
+        bytes.copy_from_slice(&to_32_bytes(&secret));
+
+        Ok(Self {
+            secret: bytes,
+            curve: PhantomData,
+        })
+    }
+
+    fn scalar(&self) -> BigUint {
+        BigUint::from_bytes_be(&self.secret)
+    }
+
+    pub fn public_key(&self) -> Point<C::Field> {
+        C::generator().scalar_mul_big(&self.scalar())
+    }
+
+    /*
+     * r = (k·G).x (mod n)
+     * s = (z + r·e) / k (mod n)
+     *
+     * `k` must be secret, unique per signature and in [1, n): two signatures sharing a
+     * nonce give away the private key. A nonce that makes r or s zero (k ≡ 0 (mod n)
+     * among them) is an error, the caller has to pick another one
+     */
+    pub fn sign_with_k(&self, z: &BigUint, k: &BigUint) -> Result<Signature, String> {
+        let n = C::order();
+        let scalar = |num: BigUint| OrderScalar::new(num, n.clone());
+        let k_inverse = scalar(k.clone())
+            .inverse()
+            .map_err(|_| "nonce k must not be 0 mod n".to_string())?;
+
+        let r = match C::generator().scalar_mul_big(k).x {
+            Some(x) => x_mod_n(&x, &n),
+            None => return Err("nonce k must not be 0 mod n".to_string()),
+        };
+        if r.num == BigUint::from(0_u32) {
+            return Err("nonce k gives r = 0".to_string());
+        }
+
+        let s = (scalar(z.clone()) + r.clone() * scalar(self.scalar())) * k_inverse;
+        if s.num == BigUint::from(0_u32) {
+            return Err("nonce k gives s = 0".to_string());
+        }
+
+        Ok(Signature::new(r.num, s.num))
+    }
+
+    /*
+     * Diffie-Hellman: e_a·P_b = e_a·e_b·G = e_b·P_a, both sides end up with the same point
+     * without either secret leaving its owner
+     */
+    pub fn ecdh(&self, their_pubkey: &Point<C::Field>) -> Point<C::Field> {
+        their_pubkey.scalar_mul_big(&self.scalar())
+    }
+
+    // the x-coordinate of the shared point, what usually goes into a KDF
+    pub fn shared_x(&self, their_pubkey: &Point<C::Field>) -> Option<C::Field> {
+        self.ecdh(their_pubkey).x
+    }
+
+    // the secret as 64 lowercase hex digits, zero-padded, with no 0x prefix
+    pub fn secret_hex(&self) -> String {
+        hex::encode(self.secret)
+    }
+
+    /*
+     * `sign_with_k` with the RFC 6979 nonce for this key and `z`, moving on to the next
+     * nonce of the DRBG in the (practically impossible) case that r or s comes out zero
+     */
+    pub fn sign(&self, z: &BigUint) -> Signature {
+        Rfc6979::new(&self.scalar(), z, &C::order())
+            .find_map(|k| self.sign_with_k(z, &k).ok())
+            .expect("the RFC 6979 nonces never run out")
+    }
+
+    // `sign_with_k` with a nonce drawn from the thread-local CSPRNG
+    #[cfg(feature = "rand")]
+    pub fn sign_random(&self, z: &BigUint) -> Signature {
+        use rand::RngCore;
+
+        let n = C::order();
+        let mut bytes = [0_u8; 32];
+
+        loop {
+            rand::thread_rng().fill_bytes(&mut bytes);
+            let k = BigUint::from_bytes_be(&bytes);
+
+            if k != BigUint::from(0_u32) && k < n {
+                if let Ok(signature) = self.sign_with_k(z, &k) {
+                    return signature;
+                }
+            }
+        }
+    }
+}
+
+// the Bitcoin side of a key: secp256k1 only
+impl PrivateKey {
+    pub fn new(secret: BigUint) -> Result<Self, String> {
+        Self::from_secret(secret)
+    }
+
+    /*
+     * Wallet Import Format: Base58Check of
+     * 0x80 (mainnet) / 0xef (testnet) || 32-byte secret || 0x01 if the public key is compressed
+     */
+    pub fn to_wif(&self, compressed: bool, testnet: bool) -> String {
+        let mut payload = vec![if testnet { 0xef } else { 0x80 }];
+        payload.extend_from_slice(&self.secret);
+
+        if compressed {
+            payload.push(0x01);
+        }
+
+        base58::encode_check(&payload)
+    }
+
+    // the key along with the (compressed, testnet) flags it was exported with
+    pub fn from_wif(s: &str) -> Result<(PrivateKey, bool, bool), String> {
+        let payload = base58::decode_check(s)?;
+
+        let testnet = match payload.first() {
+            Some(0x80) => false,
+            Some(0xef) => true,
+            Some(version) => return Err(format!("unknown WIF version byte 0x{:02x}", version)),
+            None => return Err("invalid WIF payload length 0".to_string()),
+        };
+
+        let compressed = match payload.len() {
+            33 => false,
+            34 if payload[33] == 0x01 => true,
+            34 => {
+                return Err(format!(
+                    "invalid WIF compression flag 0x{:02x}",
+                    payload[33]
+                ))
+            }
+            length => return Err(format!("invalid WIF payload length {}", length)),
+        };
+
+        let private_key = PrivateKey::new(BigUint::from_bytes_be(&payload[1..33]))?;
+
+        Ok((private_key, compressed, testnet))
+    }
+
+    // the secret as 64 hex digits, either case, with no 0x prefix
+    pub fn from_hex(s: &str) -> Result<PrivateKey, String> {
+        if s.len() != 64 {
+            return Err(format!(
+                "private key hex must be 64 characters, got {}",
+                s.len()
+            ));
+        }
+
+        let bytes = hex::decode(s).map_err(|e| format!("invalid private key hex: {}", e))?;
+
+        PrivateKey::new(BigUint::from_bytes_be(&bytes))
+    }
+}
+
+// the DRBG state and key bytes in `deterministic_k`, wiped as they are replaced or dropped
+#[cfg(feature = "zeroize")]
+type NonceBuffer = zeroize::Zeroizing<Vec<u8>>;
+#[cfg(not(feature = "zeroize"))]
+type NonceBuffer = Vec<u8>;
+
+#[cfg(feature = "zeroize")]
+impl<C: WeierstrassCurve> zeroize::Zeroize for PrivateKey<C> {
+    fn zeroize(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: WeierstrassCurve> Drop for PrivateKey<C> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: WeierstrassCurve> zeroize::ZeroizeOnDrop for PrivateKey<C> {}
+
+/*
+ * z = hash256(message) read as a big-endian integer, reduced mod n: the number `sign` and
+ * `verify` actually work with
+ */
+pub fn message_hash(message: &[u8], n: &BigUint) -> OrderScalar {
+    OrderScalar::new(BigUint::from_bytes_be(&hash256(message)), n.clone())
+}
+
+/*
+ * RFC 6979 nonce: HMAC-SHA256 as a DRBG seeded with the secret and the message hash,
+ * so the same (secret, z) always signs with the same k and no RNG is involved
+ *
+ * Works for any order n, with qlen = bits(n): the secret and z go in as rlen = ⌈qlen/8⌉
+ * bytes (int2octets) after reducing them mod n, so any size of either is accepted; `z`
+ * is the number being signed, i.e. the hash already through bits2int. Each candidate is
+ * the leftmost qlen bits of the DRBG output (bits2int), those outside of [1, n) are
+ * skipped by stepping the DRBG once more. Panics for n < 2, which has no nonces
+ */
+pub fn deterministic_k(secret: &BigUint, z: &BigUint, n: &BigUint) -> BigUint {
+    Rfc6979::new(secret, z, n)
+        .next()
+        .expect("the RFC 6979 nonces never run out")
+}
+
+fn hmac(key: &[u8], parts: &[&[u8]]) -> NonceBuffer {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    for part in parts {
+        mac.update(part);
+    }
+
+    NonceBuffer::from(mac.finalize().into_bytes().to_vec())
+}
+
+/*
+ * The RFC 6979 DRBG state (K, V) as an endless iterator of nonces in [1, n): the first is
+ * `deterministic_k`, the next ones are what section 3.2 (h) falls back to when a nonce
+ * gives r = 0 or s = 0
+ */
+struct Rfc6979 {
+    k: NonceBuffer,
+    v: NonceBuffer,
+    n: BigUint,
+    // whether a nonce was handed out, after which K and V are stepped before the next one
+    stepped: bool,
+}
+
+impl Rfc6979 {
+    fn new(secret: &BigUint, z: &BigUint, n: &BigUint) -> Self {
+        assert!(n.bits() > 1, "there are no nonces in [1, n) for n < 2");
+
+        let secret_bytes = NonceBuffer::from(int2octets(&(secret % n), n));
+        let z_bytes = int2octets(&(z % n), n);
+
+        let mut k = NonceBuffer::from(vec![0_u8; 32]);
+        let mut v = NonceBuffer::from(vec![1_u8; 32]);
+
+        k = hmac(&k, &[&v, &[0x00], &secret_bytes, &z_bytes]);
+        v = hmac(&k, &[&v]);
+        k = hmac(&k, &[&v, &[0x01], &secret_bytes, &z_bytes]);
+        v = hmac(&k, &[&v]);
+
+        Self {
+            k,
+            v,
+            n: n.clone(),
+            stepped: false,
+        }
+    }
+}
+
+impl Iterator for Rfc6979 {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        loop {
+            if self.stepped {
+                self.k = hmac(&self.k, &[&self.v, &[0x00]]);
+                self.v = hmac(&self.k, &[&self.v]);
+            }
+            self.stepped = true;
+
+            // bits2int: as many DRBG outputs as it takes to cover qlen bits, then the
+            // leftmost qlen of them
+            let qlen = self.n.bits();
+            let mut t = NonceBuffer::from(Vec::new());
+            while (t.len() as u64) * 8 < qlen {
+                self.v = hmac(&self.k, &[&self.v]);
+                t.extend_from_slice(&self.v);
+            }
+            let candidate = BigUint::from_bytes_be(&t) >> (t.len() as u64 * 8 - qlen);
+
+            if candidate != BigUint::from(0_u32) && candidate < self.n {
+                return Some(candidate);
+            }
+        }
+    }
+}
+
+// `num` < n as rlen = ⌈bits(n)/8⌉ big-endian bytes
+fn int2octets(num: &BigUint, n: &BigUint) -> Vec<u8> {
+    let bytes = num.to_bytes_be();
+    let mut padded = vec![0_u8; n.bits().div_ceil(8) as usize - bytes.len()];
+    padded.extend(bytes);
+
+    padded
+}
+
+// r = x mod n, the x-coordinate of a point read as an integer
+fn x_mod_n<F: PrimeField>(x: &F, n: &BigUint) -> OrderScalar {
+    OrderScalar::new(BigUint::from_bytes_be(&x.to_be_bytes()), n.clone())
+}
+
+pub(crate) fn to_32_bytes(num: &BigUint) -> Vec<u8> {
+    let bytes = num.to_bytes_be();
+    let mut padded = vec![0_u8; 32 - bytes.len()];
+    padded.extend(bytes);
+
+    padded
+}
+
+/*
+ * u = z/s, v = r/s (mod n)
+ * valid iff (u·G + v·pubkey).x == r
+ *
+ * over the curve `C`, e.g. `verify::<Secp256k1>(..)`. `r` and `s` outside of [1, n) are
+ * rejected without doing any curve arithmetic
+ */
+pub fn verify<C: WeierstrassCurve>(pubkey: &Point<C::Field>, z: &BigUint, sig: &Signature) -> bool {
+    let n = C::order();
+    let zero = BigUint::from(0_u32);
+
+    if sig.r == zero || sig.s == zero || sig.r >= n || sig.s >= n {
+        return false;
+    }
+
+    let scalar = |num: BigUint| OrderScalar::new(num, n.clone());
+    let s_inverse = match scalar(sig.s.clone()).inverse() {
+        Ok(inverse) => inverse,
+        Err(_) => return false,
+    };
+    let u = scalar(z.clone()) * s_inverse.clone();
+    let v = scalar(sig.r.clone()) * s_inverse;
+
+    match C::generator().scalar_mul_big(&u.num) + pubkey.scalar_mul_big(&v.num) {
+        Ok(total) => match total.x {
+            Some(x) => x_mod_n(&x, &n).num == sig.r,
+            None => false,
+        },
+        Err(_) => false,
+    }
+}
+
+/*
+ * The public key on the curve `C` that produced `sig` over `z` (ecrecover)
+ *
+ * `r` only fixes the x-coordinate of the nonce point R = k·G (mod n), the recovery id
+ * picks the rest: bit 0 is the parity of R.y and bit 1 says R.x = r + n (only possible
+ * when r < p - n, so practically never). Then
+ *
+ * P = r⁻¹·(s·R - z·G)
+ */
+pub fn recover_pubkey<C: WeierstrassCurve>(
+    z: &BigUint,
+    sig: &Signature,
+    recovery_id: u8,
+) -> Result<Point<C::Field>, String> {
+    let n = C::order();
+    let zero = BigUint::from(0_u32);
+
+    if recovery_id > 3 {
+        return Err(format!("invalid recovery id {}", recovery_id));
+    }
+
+    if sig.r == zero || sig.s == zero || sig.r >= n || sig.s >= n {
+        return Err("signature r and s must be between 1 and n - 1".to_string());
+    }
+
+    let x = if recovery_id & 2 == 2 {
+        &sig.r + &n
+    } else {
+        sig.r.clone()
+    };
+    if x >= C::field_prime() {
+        return Err(format!(
+            "{:x} is not the x-coordinate of a point on the curve",
+            x
+        ));
+    }
+
+    let g = C::generator();
+    let x = C::a().with_be_bytes(&x.to_bytes_be())?;
+    let (even, odd) = Point::from_x(x, C::a(), C::b())?;
+    let (even, odd) = match even.y_is_even() {
+        Some(false) => (odd, even),
+        _ => (even, odd),
+    };
+    let nonce_point = if recovery_id & 1 == 1 { odd } else { even };
+
+    let r_inverse = sig.r.modpow(&(&n - BigUint::from(2_u32)), &n);
+    let u = &sig.s * &r_inverse % &n;
+    let v = (&n - z * &r_inverse % &n) % &n;
+
+    let pubkey = (nonce_point.scalar_mul_big(&u) + g.scalar_mul_big(&v))?;
+    if pubkey.is_infinity() {
+        return Err("recovered public key is the point at infinity".to_string());
+    }
+
+    Ok(pubkey)
+}
+
+/*
+ * The DER encoding as lowercase hex, what block explorers and wallets show. Formatting
+ * fails for a signature that has no DER (r or s wider than 32 bytes)
+ */
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let der = self.to_der().map_err(|_| fmt::Error)?;
+
+        write!(f, "{}", hex::encode(der))
+    }
+}
+
+// the inverse of `Display`: hex (either case), then strict `parse_der`
+impl FromStr for Signature {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let der = hex::decode(s).map_err(|e| format!("invalid signature hex: {}", e))?;
+
+        Signature::parse_der(&der)
+    }
+}
+
+// the same hex DER string as `Display` / `FromStr`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Signature {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let der = self.to_der().map_err(serde::ser::Error::custom)?;
+
+        serializer.serialize_str(&hex::encode(der))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Signature {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex_digits = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        hex_digits.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod ecdsa_tests {
+    use super::*;
+    use crate::exercises::s256::S256Point;
+
+    fn from_hex(hex: &str) -> BigUint {
+        BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    // Programming Bitcoin, chapter 3: verifying a signature
+    fn book_pubkey() -> S256Point {
+        S256Point::from_coordinates(
+            from_hex("887387e452b8eacc4acfde10d9aaf7f6d9a0f975aabb10d006e4da568744d06c"),
+            from_hex("61de6d95231cd89026e286df3b6ae4a894a3378e393e93a0f45b666329a0ae34"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify_book_vectors() {
+        let vectors = [
+            (
+                "ec208baa0fc1c19f708a9ca96fdeff3ac3f230bb4a7ba4aede4942ad003c0f60",
+                "ac8d1c87e51d0d441be8b3dd5b05c8795b48875dffe00b7ffcfac23010d3a395",
+                "68342ceff8935ededd102dd876ffd6ba72d6a427a3edb13d26eb0781cb423c4",
+            ),
+            (
+                "7c076ff316692a3d7eb3c3bb0f8b1488cf72e1afcd929e29307032997a838a3d",
+                "eff69ef2b1bd93a66ed5219add4fb51e11a840f404876325a1e8ffe0529a2c",
+                "c7207fee197d27c618aea621406f6bf5ef6fca38681d82b2f06fddbdce6feab6",
+            ),
+        ];
+
+        for (z, r, s) in vectors {
+            let signature = Signature::new(from_hex(r), from_hex(s));
+
+            assert!(verify::<Secp256k1>(
+                &book_pubkey(),
+                &from_hex(z),
+                &signature
+            ));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_signature() {
+        let z = from_hex("ec208baa0fc1c19f708a9ca96fdeff3ac3f230bb4a7ba4aede4942ad003c0f60");
+        let r = from_hex("ac8d1c87e51d0d441be8b3dd5b05c8795b48875dffe00b7ffcfac23010d3a395");
+        let s = from_hex("68342ceff8935ededd102dd876ffd6ba72d6a427a3edb13d26eb0781cb423c4");
+
+        let tampered_z = &z + BigUint::from(1_u32);
+        assert!(!verify::<Secp256k1>(
+            &book_pubkey(),
+            &tampered_z,
+            &Signature::new(r.clone(), s.clone())
+        ));
+
+        assert!(!verify::<Secp256k1>(
+            &book_pubkey(),
+            &z,
+            &Signature::new(BigUint::from(0_u32), s)
+        ));
+        assert!(!verify::<Secp256k1>(
+            &book_pubkey(),
+            &z,
+            &Signature::new(r, S256Point::order())
+        ));
+    }
+
+    #[test]
+    fn test_low_s() {
+        let n = S256Point::order();
+        let private_key = PrivateKey::new(BigUint::from(12345_u32)).unwrap();
+        let z = BigUint::from(6789_u32);
+
+        let low = private_key.sign(&z).normalize_s(&n);
+        let high = Signature::new(low.r.clone(), &n - &low.s);
+
+        assert!(low.is_low_s(&n));
+        assert!(!high.is_low_s(&n));
+        assert!(verify::<Secp256k1>(&private_key.public_key(), &z, &high));
+        assert_eq!(high.normalize_s(&n), low);
+        assert!(high.normalize_s(&n).is_low_s(&n));
+
+        // n/2 itself is still low, n/2 + 1 is not
+        let half = &n / 2_u32;
+        assert!(Signature::new(BigUint::from(1_u32), half.clone()).is_low_s(&n));
+        assert!(!Signature::new(BigUint::from(1_u32), half + 1_u32).is_low_s(&n));
+    }
+
+    #[test]
+    fn test_private_key_out_of_range() {
+        assert_eq!(
+            PrivateKey::new(BigUint::from(0_u32)),
+            Err("private key must be between 1 and n - 1".to_string())
+        );
+        assert_eq!(
+            PrivateKey::new(S256Point::order()),
+            Err("private key must be between 1 and n - 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_message_hash() {
+        let n = S256Point::order();
+
+        // Programming Bitcoin, chapter 3
+        assert_eq!(
+            message_hash(b"Programming Bitcoin!", &n).num,
+            from_hex("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48")
+        );
+        assert_eq!(
+            message_hash(b"my message", &n).num,
+            from_hex("231c6f3d980a6b0fb7152f85cee7eb52bf92433d9919b9c5218cb08e79cce78")
+        );
+
+        // the reduction is mod n, not mod p
+        let small = BigUint::from(1000_u32);
+        assert_eq!(
+            message_hash(b"my message", &small),
+            OrderScalar::new(
+                from_hex("231c6f3d980a6b0fb7152f85cee7eb52bf92433d9919b9c5218cb08e79cce78"),
+                small
+            )
+        );
+    }
+
+    #[test]
+    fn test_sign_with_k() {
+        // Programming Bitcoin, chapter 3: e = 12345, z = hash256("Programming Bitcoin!")
+        let private_key = PrivateKey::new(BigUint::from(12345_u32)).unwrap();
+        let z = from_hex("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48");
+        let k = BigUint::from(1234567890_u32);
+
+        let signature = private_key.sign_with_k(&z, &k).unwrap();
+
+        assert_eq!(
+            signature,
+            Signature::new(
+                from_hex("2b698a0f0a4041b77e63488ad48c23e8e8838dd1fb7520408b121697b782ef22"),
+                from_hex("1dbc63bfef4416705e602a7b564161167076d8b20990a0f26f316cff2cb0bc1a"),
+            )
+        );
+        assert_eq!(
+            private_key.public_key(),
+            S256Point::from_coordinates(
+                from_hex("f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f"),
+                from_hex("0eba29d0f0c5408ed681984dc525982abefccd9f7ff01dd26da4999cf3f6a295"),
+            )
+            .unwrap()
+        );
+        assert!(verify::<Secp256k1>(
+            &private_key.public_key(),
+            &z,
+            &signature
+        ));
+    }
+
+    #[test]
+    fn test_sign_with_invalid_k() {
+        let private_key = PrivateKey::new(BigUint::from(12345_u32)).unwrap();
+        let n = S256Point::order();
+        let z = BigUint::from(42_u32);
+
+        for k in [BigUint::from(0_u32), n.clone(), &n * 2_u32] {
+            assert_eq!(
+                private_key.sign_with_k(&z, &k),
+                Err("nonce k must not be 0 mod n".to_string())
+            );
+        }
+
+        // k = 1 makes r = G.x and s = z + r·e, which z = -r·e (mod n) sends to zero
+        let r = S256Point::generator().x.unwrap().num % &n;
+        let z = &n - &r * BigUint::from(12345_u32) % &n;
+        assert_eq!(
+            private_key.sign_with_k(&z, &BigUint::from(1_u32)),
+            Err("nonce k gives s = 0".to_string())
+        );
+        assert!(verify::<Secp256k1>(
+            &private_key.public_key(),
+            &z,
+            &private_key.sign(&z)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sign_random() {
+        let private_key = PrivateKey::new(BigUint::from(8675309_u32)).unwrap();
+        let z = from_hex("ec208baa0fc1c19f708a9ca96fdeff3ac3f230bb4a7ba4aede4942ad003c0f60");
+
+        assert!(verify::<Secp256k1>(
+            &private_key.public_key(),
+            &z,
+            &private_key.sign_random(&z)
+        ));
+    }
+
+    #[test]
+    fn test_deterministic_k() {
+        let z = from_hex("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48");
+
+        assert_eq!(
+            deterministic_k(&BigUint::from(12345_u32), &z, &S256Point::order()),
+            from_hex("abef7a40d9bd76aef7ee7e733404ecfcd8041550a68625d7cc0608b0025038b1")
+        );
+    }
+
+    #[test]
+    fn test_deterministic_k_oversized_z() {
+        let (secret, n) = (BigUint::from(12345_u32), S256Point::order());
+        let z = from_hex("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48");
+        let k = deterministic_k(&secret, &z, &n);
+
+        // z + 5n > 2²⁵⁶ + n, more than one subtraction of n away from 32 bytes
+        for oversized in [&z + &n, &z + &n * 5_u32, &z + (&n << 300_u32)] {
+            assert_eq!(deterministic_k(&secret, &oversized, &n), k);
+        }
+
+        let k = deterministic_k(&secret, &(BigUint::from(1_u32) << 400_u32), &n);
+        assert!(k > BigUint::from(0_u32) && k < n);
+
+        // and so is the secret
+        assert_eq!(
+            deterministic_k(&(&secret + (&n << 300_u32)), &z, &n),
+            from_hex("abef7a40d9bd76aef7ee7e733404ecfcd8041550a68625d7cc0608b0025038b1")
+        );
+    }
+
+    #[test]
+    fn test_deterministic_k_small_order() {
+        // qlen = 7: each candidate is the top 7 bits of the 32-byte DRBG output
+        let nonces: Vec<BigUint> = Rfc6979::new(
+            &BigUint::from(23_u32),
+            &BigUint::from(51_u32),
+            &BigUint::from(79_u32),
+        )
+        .take(5)
+        .collect();
+        assert_eq!(nonces, [69_u32, 61, 9, 22, 16].map(BigUint::from).to_vec());
+
+        for (secret, z, n) in [(1_u32, 0_u32, 2_u32), (6, 300, 7), (12345, 678, 65537)] {
+            let (secret, z, n) = (BigUint::from(secret), BigUint::from(z), BigUint::from(n));
+            let k = deterministic_k(&secret, &z, &n);
+
+            assert!(k > BigUint::from(0_u32) && k < n);
+        }
+        assert_eq!(
+            deterministic_k(
+                &BigUint::from(6_u32),
+                &BigUint::from(300_u32),
+                &BigUint::from(7_u32)
+            ),
+            BigUint::from(3_u32)
+        );
+    }
+
+    #[test]
+    fn test_sign_deterministic() {
+        let vectors = [
+            (
+                12345_u32,
+                // hash256("Programming Bitcoin!")
+                "969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48",
+                "8eeacac05e4c29e793b5287ed044637132ce9ead7fded533e7441d87a8dc9c23",
+                "36674f81f10c7fb347c1224bd546813ea24ada6f642c02f2248516e3aa8cb303",
+            ),
+            (
+                8675309_u32,
+                // hash256("my message")
+                "0231c6f3d980a6b0fb7152f85cee7eb52bf92433d9919b9c5218cb08e79cce78",
+                "6a7f8e4797d78c644dd2b61650e05986ee589fa98ee297b9442c6161db47f58f",
+                "60d6c3b2cf862916ed59a75a13e0e75f4797d6eeaa98e35b15a74ffd9fc45c77",
+            ),
+        ];
+
+        for (secret, z, r, s) in vectors {
+            let private_key = PrivateKey::new(BigUint::from(secret)).unwrap();
+            let z = from_hex(z);
+            let signature = private_key.sign(&z);
+
+            assert_eq!(signature, Signature::new(from_hex(r), from_hex(s)));
+            assert_eq!(private_key.sign(&z), signature);
+            assert!(verify::<Secp256k1>(
+                &private_key.public_key(),
+                &z,
+                &signature
+            ));
+        }
+    }
+
+    #[test]
+    fn test_to_der() {
+        // Programming Bitcoin, chapter 4: `s` has its high bit set and gets the 0x00 pad
+        let signature = Signature::new(
+            from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"),
+            from_hex("8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"),
+        );
+        assert_eq!(
+            to_hex(&signature.to_der().unwrap()),
+            "3045022037206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6\
+             0221008ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"
+        );
+
+        let swapped = Signature::new(signature.s.clone(), signature.r.clone());
+        assert_eq!(
+            to_hex(&swapped.to_der().unwrap()),
+            "30450221008ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec\
+             022037206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"
+        );
+    }
+
+    #[test]
+    fn test_to_der_minimal() {
+        let signature = Signature::new(BigUint::from(1_u32), BigUint::from(0x80_u32));
+
+        assert_eq!(to_hex(&signature.to_der().unwrap()), "300702010102020080");
+    }
+
+    #[test]
+    fn test_parse_der_round_trip() {
+        let signatures = [
+            Signature::new(
+                from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"),
+                from_hex("8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"),
+            ),
+            Signature::new(BigUint::from(1_u32), BigUint::from(0x80_u32)),
+            PrivateKey::new(BigUint::from(12345_u32))
+                .unwrap()
+                .sign(&BigUint::from(42_u32)),
+        ];
+
+        for signature in signatures {
+            assert_eq!(
+                Signature::parse_der(&signature.to_der().unwrap()),
+                Ok(signature)
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_from_str_round_trip() {
+        let signature = PrivateKey::new(BigUint::from(12345_u32))
+            .unwrap()
+            .sign(&BigUint::from(42_u32));
+
+        assert_eq!(signature.to_string(), to_hex(&signature.to_der().unwrap()));
+        assert_eq!(
+            signature.to_string().parse::<Signature>(),
+            Ok(signature.clone())
+        );
+        assert_eq!(
+            signature.to_string().to_uppercase().parse::<Signature>(),
+            Ok(signature)
+        );
+
+        let small = Signature::new(BigUint::from(1_u32), BigUint::from(0x80_u32));
+        assert_eq!(small.to_string(), "300702010102020080");
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert_eq!(
+            "30070201010202008".parse::<Signature>(),
+            Err("invalid signature hex: Odd number of digits".to_string())
+        );
+        assert_eq!(
+            "3007020101020200xx".parse::<Signature>(),
+            Err("invalid signature hex: Invalid character 'x' at position 16".to_string())
+        );
+        assert_eq!(
+            "310702010102020080".parse::<Signature>(),
+            Err("invalid DER sequence marker 0x31".to_string())
+        );
+    }
+
+    #[test]
+    fn test_compact_round_trip() {
+        let n = S256Point::order();
+        let signatures = [
+            Signature::new(
+                from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"),
+                from_hex("8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"),
+            ),
+            Signature::new(BigUint::from(1_u32), &n - 1_u32),
+        ];
+
+        for signature in signatures {
+            let compact = signature.to_compact().unwrap();
+
+            assert_eq!(Signature::from_compact(&compact, &n), Ok(signature));
+        }
+
+        let compact = Signature::new(BigUint::from(1_u32), BigUint::from(2_u32))
+            .to_compact()
+            .unwrap();
+        assert_eq!((compact[31], compact[63]), (1, 2));
+        assert!(compact[..31]
+            .iter()
+            .chain(&compact[32..63])
+            .all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_to_compact_oversized() {
+        let wide = BigUint::from(1_u32) << 256_u32;
+
+        assert_eq!(
+            Signature::new(wide.clone(), BigUint::from(1_u32)).to_compact(),
+            Err("r does not fit in 32 bytes".to_string())
+        );
+        assert_eq!(
+            Signature::new(BigUint::from(1_u32), wide << 100_u32).to_compact(),
+            Err("s does not fit in 32 bytes".to_string())
+        );
+
+        let widest = (BigUint::from(1_u32) << 256_u32) - 1_u32;
+        assert_eq!(
+            Signature::new(widest.clone(), widest).to_compact(),
+            Ok([0xff; 64])
+        );
+    }
+
+    #[test]
+    fn test_from_compact_out_of_range() {
+        let n = S256Point::order();
+
+        assert_eq!(
+            Signature::from_compact(
+                &Signature::new(BigUint::from(1_u32), n.clone())
+                    .to_compact()
+                    .unwrap(),
+                &n
+            ),
+            Err("s must be between 1 and n - 1".to_string())
+        );
+        assert_eq!(
+            Signature::from_compact(&[0xff; 64], &n),
+            Err("r must be between 1 and n - 1".to_string())
+        );
+        assert_eq!(
+            Signature::from_compact(&[0; 64], &n),
+            Err("r must be between 1 and n - 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_der_malformed() {
+        let der = Signature::new(BigUint::from(1_u32), BigUint::from(0x80_u32))
+            .to_der()
+            .unwrap();
+
+        assert_eq!(
+            Signature::parse_der(&[]),
+            Err("DER signature is truncated".to_string())
+        );
+        assert_eq!(
+            Signature::parse_der(&[&[0x31], &der[1..]].concat()),
+            Err("invalid DER sequence marker 0x31".to_string())
+        );
+        assert_eq!(
+            Signature::parse_der(&der[..der.len() - 1]),
+            Err("DER length 7 does not match the 6 bytes that follow it".to_string())
+        );
+        assert_eq!(
+            Signature::parse_der(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x03, 0x01, 0x01]),
+            Err("invalid DER integer marker 0x03".to_string())
+        );
+        assert_eq!(
+            Signature::parse_der(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x02, 0x00]),
+            Err("DER signature is truncated".to_string())
+        );
+        assert_eq!(
+            Signature::parse_der(&[0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01, 0xff]),
+            Err("1 unexpected bytes after the DER signature".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_der_oversized() {
+        // 0x30 <length> 0x02 <length> <r> 0x02 0x01 0x01, built by hand as `to_der`
+        // refuses to encode these
+        let with_r = |r: &[u8]| {
+            let mut body = vec![0x02, r.len() as u8];
+            body.extend(r);
+            body.extend([0x02, 0x01, 0x01]);
+
+            let mut der = vec![0x30, body.len() as u8];
+            der.extend(body);
+            der
+        };
+
+        // a well-formed, minimal 200-byte r; 0x00 and 33 more bytes; 33 bytes without the
+        // sign byte (2²⁵⁶)
+        let wide = [0x01].into_iter().chain([0x00; 199]).collect::<Vec<u8>>();
+        let sign_byte = [0x00, 0x80]
+            .into_iter()
+            .chain([0x00; 32])
+            .collect::<Vec<u8>>();
+        let unsigned = [0x01].into_iter().chain([0x00; 32]).collect::<Vec<u8>>();
+
+        for r in [wide, sign_byte, unsigned] {
+            assert_eq!(
+                Signature::parse_der(&with_r(&r)),
+                Err("DER integer does not fit in 32 bytes".to_string())
+            );
+        }
+
+        // the widest that is accepted: 0x00 then 32 bytes with the high bit set
+        let widest = [0x00].into_iter().chain([0xff; 32]).collect::<Vec<u8>>();
+        let signature = Signature::new(
+            (BigUint::from(1_u32) << 256_u32) - 1_u32,
+            BigUint::from(1_u32),
+        );
+        assert_eq!(
+            Signature::parse_der(&with_r(&widest)),
+            Ok(signature.clone())
+        );
+        assert_eq!(signature.to_der(), Ok(with_r(&widest)));
+    }
+
+    #[test]
+    fn test_to_der_oversized() {
+        let one = BigUint::from(1_u32);
+        let wide = BigUint::from(1_u32) << 256_u32;
+
+        assert_eq!(
+            Signature::new(wide.clone(), one.clone()).to_der(),
+            Err("r does not fit in 32 bytes".to_string())
+        );
+        assert_eq!(
+            Signature::new(one.clone(), wide).to_der(),
+            Err("s does not fit in 32 bytes".to_string())
+        );
+        // these used to come out with a long-form-looking length and a wrapped one
+        assert!(Signature::new(one.clone() << 1100_u32, one)
+            .to_der()
+            .is_err());
+        let huge = BigUint::from(1_u32) << 1000_u32;
+        let signature = Signature::new(huge.clone(), huge);
+        assert!(signature.to_der().is_err());
+
+        let mut display = String::new();
+        assert!(fmt::write(&mut display, format_args!("{}", signature)).is_err());
+    }
+
+    #[test]
+    fn test_parse_der_non_minimal() {
+        let r = [0x01];
+        let s = [0x80];
+
+        // 0x00 0x01: a leading zero that isn't needed
+        let padded = [0x30, 0x08, 0x02, 0x02, 0x00, r[0], 0x02, 0x02, 0x00, s[0]];
+        assert_eq!(
+            Signature::parse_der(&padded),
+            Err("DER integer has excess leading zeros".to_string())
+        );
+
+        // 0x80 without its 0x00: reads as negative
+        let negative = [0x30, 0x06, 0x02, 0x01, r[0], 0x02, 0x01, s[0]];
+        assert_eq!(
+            Signature::parse_der(&negative),
+            Err("negative DER integer".to_string())
+        );
+
+        // 0x81 0x07: a long-form length for a short sequence
+        let long_length = [0x30, 0x81, 0x07, 0x02, 0x01, r[0], 0x02, 0x02, 0x00, s[0]];
+        assert_eq!(
+            Signature::parse_der(&long_length),
+            Err("DER length 129 does not match the 8 bytes that follow it".to_string())
+        );
+
+        let expected = Signature::new(BigUint::from(1_u32), BigUint::from(0x80_u32));
+        let minimal = expected.to_der().unwrap();
+        assert_eq!(Signature::parse_der(&minimal), Ok(expected.clone()));
+
+        for der in [&padded[..], &negative, &long_length, &minimal] {
+            assert_eq!(Signature::parse_der_lax(der), Ok(expected.clone()));
+        }
+    }
+
+    #[test]
+    fn test_parse_der_lax() {
+        let signature = PrivateKey::new(BigUint::from(12345_u32))
+            .unwrap()
+            .sign(&BigUint::from(42_u32));
+        let der = signature.to_der().unwrap();
+
+        // 0x82 0x00 <len> lengths everywhere, zero-padded integers and trailing bytes
+        let integer = |num: &BigUint| {
+            let mut bytes = vec![0x02, 0x82, 0x00, 35, 0x00, 0x00, 0x00];
+            bytes.extend(to_32_bytes(num));
+            bytes
+        };
+        let mut body = integer(&signature.r);
+        body.extend(integer(&signature.s));
+        let mut sloppy = vec![0x30, 0x84, 0x00, 0x00, 0x00, body.len() as u8];
+        sloppy.extend(body);
+        sloppy.extend([0x01]);
+
+        assert!(Signature::parse_der(&sloppy).is_err());
+        assert_eq!(Signature::parse_der_lax(&sloppy), Ok(signature.clone()));
+        assert_eq!(Signature::parse_der_lax(&der), Signature::parse_der(&der));
+
+        // still malformed
+        assert_eq!(
+            Signature::parse_der_lax(&der[..der.len() - 1]),
+            Err("DER signature is truncated".to_string())
+        );
+        assert_eq!(
+            Signature::parse_der_lax(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x03, 0x01, 0x01]),
+            Err("invalid DER integer marker 0x03".to_string())
+        );
+        let mut oversized = vec![0x30, 0x26, 0x02, 0x21, 0x01];
+        oversized.extend([0; 32]);
+        oversized.extend([0x02, 0x01, 0x01]);
+        assert_eq!(
+            Signature::parse_der_lax(&oversized),
+            Err("DER integer does not fit in 32 bytes".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_wif() {
+        let private_key = PrivateKey::new(BigUint::from(5003_u32)).unwrap();
+
+        assert_eq!(
+            private_key.to_wif(true, false),
+            "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFUqzioMfUXC"
+        );
+        assert_eq!(
+            private_key.to_wif(true, true),
+            "cMahea7zqjxrtgAbB7LSGbcQUr1uX1ojuat9jZodMN8rFTv2sfUK"
+        );
+        assert_eq!(
+            private_key.to_wif(false, false),
+            "5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsrou4T5tkU"
+        );
+        assert_eq!(
+            private_key.to_wif(false, true),
+            "91avARGdfge8E4tZfYLoxeJ5sGBdNJQH4kvjJoQFacbrgAp2YC8"
+        );
+    }
+
+    #[test]
+    fn test_from_wif_round_trip() {
+        let private_key = PrivateKey::new(from_hex("54321deadbeef")).unwrap();
+
+        for compressed in [false, true] {
+            for testnet in [false, true] {
+                assert_eq!(
+                    PrivateKey::from_wif(&private_key.to_wif(compressed, testnet)),
+                    Ok((private_key.clone(), compressed, testnet))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_wif_invalid() {
+        assert_eq!(
+            PrivateKey::from_wif("KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFUqzioMfUXD"),
+            Err("invalid base58check checksum".to_string())
+        );
+
+        let mut payload = vec![0x81];
+        payload.extend(to_32_bytes(&BigUint::from(5003_u32)));
+        assert_eq!(
+            PrivateKey::from_wif(&base58::encode_check(&payload)),
+            Err("unknown WIF version byte 0x81".to_string())
+        );
+
+        payload[0] = 0x80;
+        payload.push(0x02);
+        assert_eq!(
+            PrivateKey::from_wif(&base58::encode_check(&payload)),
+            Err("invalid WIF compression flag 0x02".to_string())
+        );
+
+        payload.truncate(20);
+        assert_eq!(
+            PrivateKey::from_wif(&base58::encode_check(&payload)),
+            Err("invalid WIF payload length 20".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_hex_round_trip() {
+        let hex = "00000000000000000000000000000000000000000000000000054321deadbeef";
+        let private_key = PrivateKey::from_hex(hex).unwrap();
+
+        assert_eq!(
+            private_key,
+            PrivateKey::new(from_hex("54321deadbeef")).unwrap()
+        );
+        assert_eq!(private_key.secret_hex(), hex);
+        assert_eq!(
+            PrivateKey::from_hex(&hex.to_uppercase()),
+            Ok(private_key.clone())
+        );
+
+        let largest = PrivateKey::new(S256Point::order() - 1_u32).unwrap();
+        assert_eq!(PrivateKey::from_hex(&largest.secret_hex()), Ok(largest));
+    }
+
+    #[test]
+    fn test_from_hex_invalid() {
+        assert_eq!(
+            PrivateKey::from_hex("54321deadbeef"),
+            Err("private key hex must be 64 characters, got 13".to_string())
+        );
+        assert_eq!(
+            PrivateKey::from_hex(&format!("0x{}", "1".repeat(64))),
+            Err("private key hex must be 64 characters, got 66".to_string())
+        );
+        assert_eq!(
+            PrivateKey::from_hex(&format!("{}g", "0".repeat(63))),
+            Err("invalid private key hex: Invalid character 'g' at position 63".to_string())
+        );
+
+        let out_of_range = Err("private key must be between 1 and n - 1".to_string());
+        assert_eq!(PrivateKey::from_hex(&"0".repeat(64)), out_of_range);
+        assert_eq!(
+            PrivateKey::from_hex(&format!("{:064x}", S256Point::order())),
+            out_of_range
+        );
+        assert_eq!(PrivateKey::from_hex(&"f".repeat(64)), out_of_range);
+    }
+
+    #[test]
+    fn test_recover_pubkey() {
+        let z = from_hex("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48");
+
+        for (secret, k) in [(12345_u32, 1234567890_u32), (8675309, 42), (5003, 7)] {
+            let private_key = PrivateKey::new(BigUint::from(secret)).unwrap();
+            let signature = private_key.sign_with_k(&z, &BigUint::from(k)).unwrap();
+
+            let nonce_point = S256Point::generator().scalar_mul(k);
+            let recovery_id = !nonce_point.y_is_even().unwrap() as u8;
+
+            assert_eq!(
+                recover_pubkey::<Secp256k1>(&z, &signature, recovery_id),
+                Ok(private_key.public_key())
+            );
+            assert_ne!(
+                recover_pubkey::<Secp256k1>(&z, &signature, recovery_id ^ 1),
+                Ok(private_key.public_key())
+            );
+        }
+    }
+
+    #[test]
+    fn test_recover_pubkey_invalid() {
+        let signature = Signature::new(BigUint::from(1_u32), BigUint::from(1_u32));
+
+        assert_eq!(
+            recover_pubkey::<Secp256k1>(&BigUint::from(1_u32), &signature, 4),
+            Err("invalid recovery id 4".to_string())
+        );
+        assert_eq!(
+            recover_pubkey::<Secp256k1>(
+                &BigUint::from(1_u32),
+                &Signature::new(BigUint::from(0_u32), BigUint::from(1_u32)),
+                0
+            ),
+            Err("signature r and s must be between 1 and n - 1".to_string())
+        );
+        assert!(recover_pubkey::<Secp256k1>(&BigUint::from(1_u32), &signature, 2).is_err());
+    }
+
+    #[test]
+    fn test_ecdh() {
+        let alice = PrivateKey::new(from_hex("54321deadbeef")).unwrap();
+        let bob = PrivateKey::new(BigUint::from(8675309_u32)).unwrap();
+
+        let alice_shared = alice.shared_x(&bob.public_key());
+        assert!(alice_shared.is_some());
+        assert_eq!(alice_shared, bob.shared_x(&alice.public_key()));
+        assert_eq!(
+            alice.ecdh(&bob.public_key()),
+            S256Point::generator().scalar_mul_big(&(from_hex("54321deadbeef") * 8675309_u32))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let signature = Signature::new(BigUint::from(1_u32), BigUint::from(0x80_u32));
+        let json = serde_json::to_string(&signature).unwrap();
+
+        assert_eq!(json, r#""300702010102020080""#);
+        assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), signature);
+        assert_eq!(
+            serde_json::from_str::<Signature>(r#""310702010102020080""#)
+                .unwrap_err()
+                .to_string(),
+            "invalid DER sequence marker 0x31"
+        );
+    }
+
+    #[test]
+    fn test_private_key_eq() {
+        let key = |secret: u32| PrivateKey::new(BigUint::from(secret)).unwrap();
+
+        assert_eq!(key(12345), key(12345));
+        assert_ne!(key(12345), key(12344));
+        // differing in the first byte only
+        assert_ne!(
+            PrivateKey::new(BigUint::from(12345_u32) + (BigUint::from(1_u32) << 248_u32)).unwrap(),
+            key(12345)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn test_secret_is_wiped() {
+        use zeroize::Zeroize;
+
+        // `Drop` calls `zeroize`, whose effect can be looked at without reading freed memory
+        fn wiped_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+        wiped_on_drop::<PrivateKey>();
+
+        let mut private_key = PrivateKey::new(BigUint::from(12345_u32)).unwrap();
+        assert_ne!(private_key.secret, [0_u8; 32]);
+
+        private_key.zeroize();
+        assert_eq!(private_key.secret, [0_u8; 32]);
+    }
+}
@@ -0,0 +1,243 @@
+use std::error::Error;
+use std::fmt;
+
+/*
+ * Everything that can go wrong in the field and curve arithmetic. Values that depend on
+ * the field type (coordinates, elements) are kept in their displayed form so the enum
+ * does not have to be generic
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum CryptoError {
+    ZeroModulus,
+    // 1, as 0 has its own variant: {0} is not a field
+    ModulusTooSmall(u64),
+    EvenModulus(u32),
+    DifferentFields {
+        operation: &'static str,
+    },
+    DivByZero,
+    NotQuadraticResidue {
+        num: String,
+    },
+    NoUniqueRoot {
+        n: u32,
+        modulus: u32,
+    },
+    TooManyBytes {
+        length: usize,
+        field: &'static str,
+    },
+    NotInRange {
+        num: String,
+        prime: String,
+    },
+    NotOnCurve {
+        x: String,
+        y: String,
+        // both sides of the curve equation at (x, y), which should have been equal
+        lhs: String,
+        rhs: String,
+    },
+    InvalidInfinity,
+    SingularCurve {
+        a: String,
+        b: String,
+    },
+    DifferentCurves,
+    MixedFields,
+    NotXCoordinate {
+        x: String,
+    },
+    XOnlyInfinity,
+    EmptySec,
+    InvalidSecCoordinate(Box<CryptoError>),
+    InvalidSecPrefix(u8),
+    InvalidSecLength {
+        length: usize,
+        prefix: u8,
+        expected: usize,
+    },
+    HasseBoundExceeded {
+        bound: u64,
+        prime: u64,
+    },
+    InvalidWindow(u8),
+    InvalidNafWidth(u8),
+    EvenOrder(u32),
+    EmptySum,
+    ScalarTooShort {
+        bits: usize,
+        length: usize,
+    },
+}
+
+impl fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CryptoError::ZeroModulus => {
+                write!(f, "cannot define a finite field over modulus ZERO")
+            }
+            CryptoError::ModulusTooSmall(modulus) => {
+                write!(
+                    f,
+                    "modulus {} is too small, a field needs a modulus of at least 2",
+                    modulus
+                )
+            }
+            CryptoError::EvenModulus(modulus) => {
+                write!(f, "Montgomery form needs an odd modulus, got {}", modulus)
+            }
+            CryptoError::DifferentFields { operation } => write!(
+                f,
+                "cannot safely {} two numbers in distinct fields",
+                operation
+            ),
+            CryptoError::DivByZero => write!(f, "cannot divide a field element by zero"),
+            CryptoError::NotQuadraticResidue { num } => {
+                write!(f, "{} is not a quadratic residue", num)
+            }
+            CryptoError::NoUniqueRoot { n, modulus } => write!(
+                f,
+                "{}-th roots in 𝔽{} are not unique, gcd({}, {}) ≠ 1",
+                n,
+                modulus,
+                n,
+                modulus - 1
+            ),
+            CryptoError::TooManyBytes { length, field } => {
+                write!(f, "{} bytes do not fit a {} field element", length, field)
+            }
+            CryptoError::NotInRange { num, prime } => {
+                write!(f, "{} is not less than the modulus {}", num, prime)
+            }
+            CryptoError::NotOnCurve { x, y, lhs, rhs } => write!(
+                f,
+                "coordinates ({}, {}) is not on the curve (lhs {} ≠ rhs {})",
+                x, y, lhs, rhs
+            ),
+            CryptoError::InvalidInfinity => write!(f, "Invalid infinity point"),
+            CryptoError::SingularCurve { a, b } => write!(
+                f,
+                "the curve with a = {}, b = {} is singular (4a³ + 27b² = 0)",
+                a, b
+            ),
+            CryptoError::DifferentCurves => {
+                write!(f, "Point addition invalid on different curves")
+            }
+            CryptoError::MixedFields => write!(f, "points span different fields"),
+            CryptoError::NotXCoordinate { x } => {
+                write!(f, "{} is not the x-coordinate of a point on the curve", x)
+            }
+            CryptoError::XOnlyInfinity => {
+                write!(f, "x-only multiplication resulted in the point at infinity")
+            }
+            CryptoError::EmptySec => write!(f, "empty SEC encoding"),
+            CryptoError::InvalidSecCoordinate(_) => write!(f, "invalid SEC coordinate"),
+            CryptoError::InvalidSecPrefix(prefix) => {
+                write!(f, "invalid SEC prefix 0x{:02x}", prefix)
+            }
+            CryptoError::InvalidSecLength {
+                length,
+                prefix,
+                expected,
+            } => write!(
+                f,
+                "invalid SEC length {} for prefix 0x{:02x}, expected {}",
+                length, prefix, expected
+            ),
+            CryptoError::HasseBoundExceeded { bound, prime } => write!(
+                f,
+                "point order exceeds the Hasse bound {} for 𝔽{}",
+                bound, prime
+            ),
+            CryptoError::InvalidWindow(window) => {
+                write!(f, "window size {} is not between 1 and 8", window)
+            }
+            CryptoError::InvalidNafWidth(width) => {
+                write!(f, "NAF width {} is not between 2 and 8", width)
+            }
+            CryptoError::EvenOrder(order) => {
+                write!(f, "2 is not invertible modulo the even order {}", order)
+            }
+            CryptoError::EmptySum => write!(f, "cannot sum an empty list of points"),
+            CryptoError::ScalarTooShort { bits, length } => write!(
+                f,
+                "a {}-byte scalar does not have the {} bits asked for",
+                length, bits
+            ),
+        }
+    }
+}
+
+impl Error for CryptoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CryptoError::InvalidSecCoordinate(source) => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+// the encoding modules (base58, DER, WIF, ...) still report plain strings
+impl From<CryptoError> for String {
+    fn from(error: CryptoError) -> Self {
+        error.to_string()
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+    use super::*;
+
+    #[test]
+    fn test_display_matches_previous_messages() {
+        assert_eq!(
+            CryptoError::DifferentFields { operation: "add" }.to_string(),
+            "cannot safely add two numbers in distinct fields"
+        );
+        assert_eq!(
+            CryptoError::NotOnCurve {
+                x: "1 — 𝔽7".to_string(),
+                y: "2 — 𝔽7".to_string(),
+                lhs: "4 — 𝔽7".to_string(),
+                rhs: "1 — 𝔽7".to_string()
+            }
+            .to_string(),
+            "coordinates (1 — 𝔽7, 2 — 𝔽7) is not on the curve (lhs 4 — 𝔽7 ≠ rhs 1 — 𝔽7)"
+        );
+        assert_eq!(
+            CryptoError::InvalidSecLength {
+                length: 2,
+                prefix: 0x04,
+                expected: 3
+            }
+            .to_string(),
+            "invalid SEC length 2 for prefix 0x04, expected 3"
+        );
+        assert_eq!(
+            CryptoError::ModulusTooSmall(1).to_string(),
+            "modulus 1 is too small, a field needs a modulus of at least 2"
+        );
+        assert_eq!(
+            String::from(CryptoError::InvalidInfinity),
+            "Invalid infinity point"
+        );
+    }
+
+    #[test]
+    fn test_boxed_error_source() {
+        let field_error = CryptoError::NotInRange {
+            num: "255".to_string(),
+            prime: "223".to_string(),
+        };
+        let error: Box<dyn Error> =
+            Box::new(CryptoError::InvalidSecCoordinate(Box::new(field_error)));
+
+        assert_eq!(error.to_string(), "invalid SEC coordinate");
+        assert_eq!(
+            error.source().map(|source| source.to_string()),
+            Some("255 is not less than the modulus 223".to_string())
+        );
+        assert!(CryptoError::DivByZero.source().is_none());
+    }
+}
@@ -0,0 +1,176 @@
+// The base fields of the 256-bit curves
+//
+// secp256k1 and P-256 only differ in their prime, so the field type of each is stamped
+// out by `prime_field_256!` instead of being written out twice
+
+/*
+ * `prime_field_256!(Name, P)` defines `pub struct Name { pub num: BigUint }`, the
+ * integers modulo the prime given as the hex string `P`, with `new`, `prime`, the
+ * `Field` / `PrimeField` impls, `Display` (64 hex digits) and the arithmetic operators
+ *
+ * The modulus is fixed so it isn't stored. `P` has to fit in 256 bits and be ≡ 3 (mod 4),
+ * which `sqrt` relies on
+ */
+macro_rules! prime_field_256 {
+    ($name:ident, $prime:expr) => {
+        #[derive(PartialEq, Debug, Clone)]
+        pub struct $name {
+            pub num: ::num_bigint::BigUint,
+        }
+
+        impl $name {
+            pub fn new(num: ::num_bigint::BigUint) -> Self {
+                Self {
+                    num: num % Self::prime(),
+                }
+            }
+
+            pub fn prime() -> ::num_bigint::BigUint {
+                ::num_bigint::BigUint::parse_bytes($prime.as_bytes(), 16).unwrap()
+            }
+        }
+
+        impl $crate::exercises::ec_point::Field for $name {
+            fn zero(&self) -> Self {
+                Self::new(::num_bigint::BigUint::from(0_u32))
+            }
+
+            fn one(&self) -> Self {
+                Self::new(::num_bigint::BigUint::from(1_u32))
+            }
+
+            fn pow(&self, exponent: u32) -> Self {
+                Self::new(
+                    self.num
+                        .modpow(&::num_bigint::BigUint::from(exponent), &Self::prime()),
+                )
+            }
+
+            // Fermat's little theorem: a⁻¹ = a^(p - 2)
+            fn mul_inverse(&self) -> Self {
+                let prime = Self::prime();
+                let exponent = &prime - ::num_bigint::BigUint::from(2_u32);
+
+                Self::new(self.num.modpow(&exponent, &prime))
+            }
+
+            fn add_inverse(&self) -> Self {
+                Self::new(Self::prime() - &self.num)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.num == ::num_bigint::BigUint::from(0_u32)
+            }
+
+            fn scalar_mul(&self, by: u32) -> Self {
+                Self::new(&self.num * by)
+            }
+        }
+
+        impl $crate::exercises::ec_point::PrimeField for $name {
+            // p ≡ 3 (mod 4), so the root (when there is one) is simply a^((p + 1) / 4)
+            fn sqrt(&self) -> Result<Self, $crate::exercises::error::CryptoError> {
+                let prime = Self::prime();
+                let exponent = (&prime + ::num_bigint::BigUint::from(1_u32))
+                    / ::num_bigint::BigUint::from(4_u32);
+                let root = Self::new(self.num.modpow(&exponent, &prime));
+
+                if root.clone() * root.clone() != *self {
+                    return Err($crate::exercises::error::CryptoError::NotQuadraticResidue {
+                        num: self.to_string(),
+                    });
+                }
+
+                Ok(root)
+            }
+
+            fn to_be_bytes(&self) -> Vec<u8> {
+                let bytes = self.num.to_bytes_be();
+                let mut padded = vec![0_u8; 32 - bytes.len()];
+                padded.extend(bytes);
+
+                padded
+            }
+
+            fn with_be_bytes(
+                &self,
+                bytes: &[u8],
+            ) -> Result<Self, $crate::exercises::error::CryptoError> {
+                if bytes.len() > 32 {
+                    return Err($crate::exercises::error::CryptoError::TooManyBytes {
+                        length: bytes.len(),
+                        field: "256-bit",
+                    });
+                }
+
+                let num = ::num_bigint::BigUint::from_bytes_be(bytes);
+                if num >= Self::prime() {
+                    return Err($crate::exercises::error::CryptoError::NotInRange {
+                        num: format!("{:x}", num),
+                        prime: $prime.to_string(),
+                    });
+                }
+
+                Ok(Self::new(num))
+            }
+
+            fn is_odd(&self) -> bool {
+                self.num.bit(0)
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "{:064x}", self.num)
+            }
+        }
+
+        impl ::std::ops::Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::new(self.num + rhs.num)
+            }
+        }
+
+        impl ::std::ops::Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self::new(self.num + Self::prime() - rhs.num)
+            }
+        }
+
+        impl ::std::ops::Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self::new(self.num * rhs.num)
+            }
+        }
+
+        impl ::std::ops::Div for $name {
+            type Output = Self;
+
+            // assumes `rhs` is not ZERO
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: Self) -> Self::Output {
+                use $crate::exercises::ec_point::Field;
+
+                self * rhs.mul_inverse()
+            }
+        }
+
+        impl ::std::ops::Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                use $crate::exercises::ec_point::Field;
+
+                self.add_inverse()
+            }
+        }
+    };
+}
+
+pub(crate) use prime_field_256;
@@ -8,146 +8,365 @@
 use std::fmt;
 use std::ops;
 
-use crate::exercises::ec_point::Field;
+use crate::exercises::ec_point::{Field, PrimeField};
+use crate::exercises::error::CryptoError;
+use crate::exercises::num::gcd;
+use crate::extension_fields::sq_root::tonelli_shanks;
 
 #[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Fp {
     pub num: u32,
     pub modulus: u32,
 }
 
 impl Fp {
-    pub fn new(_num: i64, _modulus: u32) -> Result<Self, String> {
+    pub fn new(_num: i64, _modulus: u32) -> Result<Self, CryptoError> {
         // TODO: handle `_modulus` errors
         if _modulus == 0 {
-            return Err(format!("cannot define a finite field over modulus ZERO"));
+            return Err(CryptoError::ZeroModulus);
         }
 
         let m = _modulus as i64;
         let element = ((_num % m + m) % m) as u32;
 
         Ok(Self {
-            num: element as u32,
+            num: element,
             modulus: _modulus,
         })
     }
 
+    /*
+     * `new` for a `u32` that may be ≥ the modulus, which can't fail: `num` is simply
+     * reduced. Panics on a modulus of ZERO
+     */
+    pub fn new_reduced(num: u32, modulus: u32) -> Self {
+        assert!(
+            modulus != 0,
+            "cannot define a finite field over modulus ZERO"
+        );
+
+        Self {
+            num: num % modulus,
+            modulus,
+        }
+    }
+
+    /*
+     * The Legendre symbol (num / modulus) by Euler's criterion: a^((p - 1)/2) is 1 when `a`
+     * is a non-zero square and p - 1 (i.e. -1) when it isn't, since its square is a^(p-1) = 1
+     *
+     * 0 for ZERO, 1 for a quadratic residue, -1 for a non-residue
+     */
+    pub fn legendre(self) -> i8 {
+        if self.num == 0 {
+            return 0;
+        }
+
+        if self.pow((self.modulus - 1) / 2).num == 1 {
+            1
+        } else {
+            -1
+        }
+    }
+
+    // whether `sqrt` will succeed, ZERO counts as a square (0² = 0)
+    pub fn is_quadratic_residue(self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /*
+     * The r with rⁿ = self when it is unique, i.e. when gcd(n, p - 1) = 1: x ↦ xⁿ is then
+     * a permutation of 𝔽p whose inverse is x ↦ x^d, d = n⁻¹ (mod p - 1), because
+     * r^(n·d) = r^(1 + k(p - 1)) = r
+     *
+     * Otherwise there are either no roots or several, and neither case is handled yet
+     */
+    pub fn nth_root(self, n: u32) -> Result<Self, CryptoError> {
+        let order = self.modulus as i64 - 1;
+
+        if gcd(n as u64, order as u64) != 1 {
+            return Err(CryptoError::NoUniqueRoot {
+                n,
+                modulus: self.modulus,
+            });
+        }
+
+        if self.num == 0 {
+            return Ok(self);
+        }
+
+        // extended Euclid on (n, p - 1), tracking the coefficient of n
+        let (mut r0, mut r1) = (n as i64, order);
+        let (mut s0, mut s1) = (1_i64, 0_i64);
+
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (s0, s1) = (s1, s0 - q * s1);
+        }
+
+        Ok(self.pow(s0.rem_euclid(order.max(1)) as u32))
+    }
+
+    /*
+     * The running result of square-and-multiply after each step, for watching the
+     * algorithm work: the bits of `exponent` are walked from the most significant down,
+     * squaring at every bit and multiplying by `self` when it is set, so bits + ones
+     * entries ending in self^exponent. Empty for 0
+     *
+     * `pow` walks the bits the other way (see `modpow`); this order is the one
+     * `Point::scalar_mul_steps` uses, squaring for doubling and multiplying for adding
+     */
+    pub fn pow_steps(self, exponent: u32) -> Vec<Self> {
+        let mut result = self.one();
+        let mut steps = vec![];
+
+        for bit in (0..(u32::BITS - exponent.leading_zeros())).rev() {
+            result = result * result;
+            steps.push(result);
+
+            if (exponent >> bit) & 1 == 1 {
+                result = result * self;
+                steps.push(result);
+            }
+        }
+
+        steps
+    }
+
+    /*
+     * `==` without an early exit: both words are compared with XOR and folded into one
+     * value, so the time taken does not depend on where (or whether) they differ. Use it
+     * when either side is derived from a secret, e.g. checking a recomputed nonce or key
+     *
+     * `black_box` keeps the compiler from turning the fold back into two branches
+     */
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let difference = (self.num ^ other.num) | (self.modulus ^ other.modulus);
+
+        std::hint::black_box(difference) == 0
+    }
+
+    /*
+     * Another element of the same field, so the modulus doesn't have to be passed around
+     * (and can't be passed wrong). Like `try_from`, `num` has to be below the modulus
+     */
+    pub fn with_num(self, num: u32) -> Result<Self, CryptoError> {
+        Self::try_from((num, self.modulus))
+    }
+
     #[allow(dead_code)]
     pub fn scalar_mul_fe(self, by: &Self) -> Self {
         self.scalar_mul(by.num)
     }
 
     #[allow(dead_code)]
-    pub fn safe_add(self, other: Self) -> Result<Self, String> {
+    pub fn safe_add(self, other: Self) -> Result<Self, CryptoError> {
         if self.modulus != other.modulus {
-            return Err(format!("cannot safely add two numbers in distinct fields"));
+            return Err(CryptoError::DifferentFields { operation: "add" });
         }
 
         Ok(self + other)
     }
 
     #[allow(dead_code)]
-    pub fn safe_subtract(self, other: Self) -> Result<Self, String> {
+    pub fn safe_subtract(self, other: Self) -> Result<Self, CryptoError> {
         if self.modulus != other.modulus {
-            return Err(format!(
-                "cannot safely subtract two numbers in distinct fields"
-            ));
+            return Err(CryptoError::DifferentFields {
+                operation: "subtract",
+            });
         }
 
         Ok(self - other)
     }
 
     #[allow(dead_code)]
-    pub fn safe_mul(self, other: Self) -> Result<Self, String> {
+    pub fn safe_mul(self, other: Self) -> Result<Self, CryptoError> {
         if self.modulus != other.modulus {
-            return Err(format!(
-                "cannot safely multiply two numbers in distinct fields"
-            ));
+            return Err(CryptoError::DifferentFields {
+                operation: "multiply",
+            });
         }
 
         Ok(self * other)
     }
 
     #[allow(dead_code)]
-    pub fn safe_div(self, other: Self) -> Result<Self, String> {
+    pub fn safe_div(self, other: Self) -> Result<Self, CryptoError> {
         if self.modulus != other.modulus {
-            return Err(format!(
-                "cannot safely divide two numbers in distinct fields"
-            ));
+            return Err(CryptoError::DifferentFields {
+                operation: "divide",
+            });
         }
 
         if other.num == 0 {
-            return Err(format!("cannot divide a field element by zero"));
+            return Err(CryptoError::DivByZero);
         }
 
         Ok(self / other)
     }
-}
 
-impl Field for Fp {
-    fn zero(modulus: u32) -> Self {
-        Self::new(0, modulus).unwrap()
+    /*
+     * The field element equivalent of the rational `n/d`, i.e. n · d⁻¹ (mod modulus)
+     */
+    #[allow(dead_code)]
+    pub fn from_rational(n: i64, d: i64, _modulus: u32) -> Result<Self, CryptoError> {
+        Self::new(n, _modulus)?.safe_div(Self::new(d, _modulus)?)
     }
 
-    fn one(modulus: u32) -> Self {
-        Self::new(1, modulus).unwrap()
-    }
+    /*
+     * Rational reconstruction: recovers `(n, d)` with |n| < bound and 0 < d < bound
+     * such that n/d ≡ self (mod modulus), or `None` if there is no such rational
+     *
+     * Runs the extended Euclidean algorithm on (modulus, num), tracking the Bézout
+     * coefficient `t` with rᵢ ≡ tᵢ·num (mod modulus), and stops at the first remainder
+     * under `bound`; rᵢ/tᵢ is then the candidate. The answer is unique when
+     * 2·bound² ≤ modulus
+     */
+    #[allow(dead_code)]
+    pub fn rational_reconstruct(self, bound: u32) -> Option<(i64, i64)> {
+        if bound == 0 {
+            return None;
+        }
 
-    fn modulus(&self) -> u32 {
-        self.modulus
-    }
+        let bound = bound as i64;
+        let (mut r0, mut r1) = (self.modulus as i64, self.num as i64);
+        let (mut t0, mut t1) = (0_i64, 1_i64);
 
-    fn pow(self, exponent: u32) -> Self {
-        let normalized_exponent = exponent % self.modulus;
-        let mut num: u32 = self.num;
+        while r1 >= bound {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q * t1);
+        }
 
-        if exponent == 0 {
-            return Self {
-                num: 1,
-                modulus: self.modulus,
-            };
+        if t1 == 0 || t1.abs() >= bound || gcd(r1 as u64, t1.unsigned_abs()) != 1 {
+            return None;
         }
 
-        for _ in 0..(normalized_exponent - 1) {
-            num = (num * self.num) % self.modulus;
+        Some((r1 * t1.signum(), t1.abs()))
+    }
+}
+
+/*
+ * base^exponent mod `modulus` by square and multiply, on plain integers. The products are
+ * taken in u128 so any u64 modulus works; `modulus` must not be zero
+ */
+pub fn modpow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut result = 1 % modulus;
+    let mut base = base as u128 % modulus;
+    let mut exponent = exponent;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
         }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+
+    result as u64
+}
 
+impl Field for Fp {
+    fn zero(&self) -> Self {
+        Self::new(0, self.modulus).unwrap()
+    }
+
+    fn one(&self) -> Self {
+        Self::new(1, self.modulus).unwrap()
+    }
+
+    // the exponent is used as is (not reduced)
+    fn pow(&self, exponent: u32) -> Self {
         Self {
-            num: num,
+            num: modpow(self.num as u64, exponent as u64, self.modulus as u64) as u32,
             modulus: self.modulus,
         }
     }
 
-    fn mul_inverse(self) -> Self {
+    fn mul_inverse(&self) -> Self {
         self.pow(self.modulus - 2)
     }
 
-    fn add_inverse(self) -> Self {
+    fn add_inverse(&self) -> Self {
+        -*self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    fn scalar_mul(&self, by: u32) -> Self {
         Self {
-            num: self.modulus - self.num,
+            num: (self.num as u64 * by as u64 % self.modulus as u64) as u32,
             modulus: self.modulus,
         }
     }
+}
 
-    fn is_zero(self) -> bool {
-        self.num == 0
+impl PrimeField for Fp {
+    fn sqrt(&self) -> Result<Self, CryptoError> {
+        match tonelli_shanks(self) {
+            Some(root) => Self::new(root as i64, self.modulus),
+            None => Err(CryptoError::NotQuadraticResidue {
+                num: self.to_string(),
+            }),
+        }
     }
 
-    fn scalar_mul(self, by: u32) -> Self {
-        let mut num: u32 = self.num;
+    fn to_be_bytes(&self) -> Vec<u8> {
+        let width = (u32::BITS - (self.modulus - 1).leading_zeros())
+            .div_ceil(8)
+            .max(1);
 
-        if by == 0 {
-            num = 0;
-        } else if by > 1 {
-            for _ in 0..(by - 1) {
-                num = (num + self.num) % self.modulus;
-            }
+        self.num.to_be_bytes()[(4 - width as usize)..].to_vec()
+    }
+
+    fn with_be_bytes(&self, bytes: &[u8]) -> Result<Self, CryptoError> {
+        if bytes.len() > 4 {
+            return Err(CryptoError::TooManyBytes {
+                length: bytes.len(),
+                field: "u32",
+            });
         }
 
-        Self {
-            num: num,
-            modulus: self.modulus,
+        let num = bytes
+            .iter()
+            .fold(0_u64, |acc, &byte| (acc << 8) | byte as u64);
+
+        if num >= self.modulus as u64 {
+            return Err(CryptoError::NotInRange {
+                num: num.to_string(),
+                prime: self.modulus.to_string(),
+            });
         }
+
+        Self::new(num as i64, self.modulus)
+    }
+
+    fn is_odd(&self) -> bool {
+        self.num % 2 == 1
+    }
+}
+
+/*
+ * (num, modulus), the strict counterpart of `new_reduced`: a `num` that is not already
+ * reduced is an error rather than silently wrapped around
+ */
+impl TryFrom<(u32, u32)> for Fp {
+    type Error = CryptoError;
+
+    fn try_from((num, modulus): (u32, u32)) -> Result<Self, Self::Error> {
+        if modulus != 0 && num >= modulus {
+            return Err(CryptoError::NotInRange {
+                num: num.to_string(),
+                prime: modulus.to_string(),
+            });
+        }
+
+        Self::new(num as i64, modulus)
     }
 }
 
@@ -163,7 +382,7 @@ impl ops::Add for Fp {
     // assumes `rhs` has the same MODULUS
     fn add(self, rhs: Self) -> Self::Output {
         Self {
-            num: (self.num + rhs.num) % self.modulus,
+            num: ((self.num as u64 + rhs.num as u64) % self.modulus as u64) as u32,
             modulus: self.modulus,
         }
     }
@@ -224,6 +443,68 @@ impl ops::Neg for Fp {
     }
 }
 
+/*
+ * The operators on references too, `&a + &b` is `a + b`. Nothing to save for a `Copy`
+ * type, but it lets generic code written against `&F` (for big non-`Copy` fields) take `Fp`
+ */
+impl ops::Add<&Fp> for &Fp {
+    type Output = Fp;
+
+    fn add(self, rhs: &Fp) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl ops::Sub<&Fp> for &Fp {
+    type Output = Fp;
+
+    fn sub(self, rhs: &Fp) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl ops::Mul<&Fp> for &Fp {
+    type Output = Fp;
+
+    fn mul(self, rhs: &Fp) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl ops::Div<&Fp> for &Fp {
+    type Output = Fp;
+
+    fn div(self, rhs: &Fp) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+impl ops::Neg for &Fp {
+    type Output = Fp;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+/*
+ * {"num": 3, "modulus": 7}, rejecting a zero modulus or a `num` that is not reduced
+ */
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fp {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Fields {
+            num: u32,
+            modulus: u32,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+
+        Fp::try_from((fields.num, fields.modulus)).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod ff_tests {
     use super::*;
@@ -236,10 +517,7 @@ mod ff_tests {
     fn test_field_element_init_error() {
         let num: i64 = PRIME as i64;
 
-        assert_eq!(
-            Fp::new(num, ZERO),
-            Err(format!("cannot define a finite field over modulus ZERO"))
-        );
+        assert_eq!(Fp::new(num, ZERO), Err(CryptoError::ZeroModulus));
     }
 
     #[test]
@@ -262,7 +540,7 @@ mod ff_tests {
 
         assert_eq!(
             a.safe_add(b),
-            Err(format!("cannot safely add two numbers in distinct fields"))
+            Err(CryptoError::DifferentFields { operation: "add" })
         );
     }
 
@@ -287,9 +565,9 @@ mod ff_tests {
 
         assert_eq!(
             a.safe_subtract(b),
-            Err(format!(
-                "cannot safely subtract two numbers in distinct fields"
-            ))
+            Err(CryptoError::DifferentFields {
+                operation: "subtract"
+            })
         );
     }
 
@@ -314,9 +592,9 @@ mod ff_tests {
 
         assert_eq!(
             a.safe_mul(b),
-            Err(format!(
-                "cannot safely multiply two numbers in distinct fields"
-            ))
+            Err(CryptoError::DifferentFields {
+                operation: "multiply"
+            })
         );
     }
 
@@ -393,7 +671,8 @@ mod ff_tests {
     fn test_field_element_exponent_overflow() {
         let a = Fp::new(3, PRIME).unwrap();
         let exponent: u32 = 12;
-        let normalized_exponent = 12 % PRIME;
+        // Fermat: a^(p - 1) = 1, so exponents only matter mod p - 1
+        let normalized_exponent = 12 % (PRIME - 1);
 
         let computed_power = a.pow(exponent);
         let expected_power = a.pow(normalized_exponent);
@@ -408,9 +687,9 @@ mod ff_tests {
 
         assert_eq!(
             a.safe_div(b),
-            Err(format!(
-                "cannot safely divide two numbers in distinct fields"
-            ))
+            Err(CryptoError::DifferentFields {
+                operation: "divide"
+            })
         );
     }
 
@@ -419,10 +698,7 @@ mod ff_tests {
         let a = Fp::new(3, PRIME).unwrap();
         let c = Fp::new(0, PRIME).unwrap();
 
-        assert_eq!(
-            a.safe_div(c),
-            Err(format!("cannot divide a field element by zero"))
-        );
+        assert_eq!(a.safe_div(c), Err(CryptoError::DivByZero));
     }
 
     #[test]
@@ -438,4 +714,371 @@ mod ff_tests {
             }
         );
     }
+
+    #[test]
+    fn test_field_element_from_rational() {
+        let half = Fp::from_rational(1, 2, PRIME).unwrap();
+
+        assert_eq!(
+            half * Fp::new(2, PRIME).unwrap(),
+            Fp::new(1, PRIME).unwrap()
+        );
+        assert_eq!(Fp::from_rational(1, 0, PRIME), Err(CryptoError::DivByZero));
+    }
+
+    #[test]
+    fn test_field_element_rational_reconstruct() {
+        let prime: u32 = 101;
+
+        assert_eq!(
+            Fp::from_rational(1, 2, prime)
+                .unwrap()
+                .rational_reconstruct(7),
+            Some((1, 2))
+        );
+        assert_eq!(
+            Fp::from_rational(-3, 5, prime)
+                .unwrap()
+                .rational_reconstruct(7),
+            Some((-3, 5))
+        );
+        assert_eq!(
+            Fp::new(0, prime).unwrap().rational_reconstruct(7),
+            Some((0, 1))
+        );
+    }
+
+    #[test]
+    fn test_field_element_rational_reconstruct_out_of_bound() {
+        let third = Fp::from_rational(1, 3, 101).unwrap();
+
+        assert_eq!(third.rational_reconstruct(2), None);
+    }
+
+    #[test]
+    fn test_field_element_sqrt() {
+        let prime: u32 = 223;
+
+        for num in 0..prime as i64 {
+            let square = Fp::new(num, prime).unwrap().pow(2);
+            let root = square.sqrt().unwrap();
+
+            assert_eq!(root.pow(2), square);
+        }
+    }
+
+    #[test]
+    fn test_field_element_sqrt_error() {
+        let a = Fp::new(3, PRIME).unwrap();
+
+        assert_eq!(
+            a.sqrt(),
+            Err(CryptoError::NotQuadraticResidue {
+                num: "3 — 𝔽7".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_field_element_bytes() {
+        assert_eq!(Fp::new(6, PRIME).unwrap().to_be_bytes(), vec![0x06]);
+        assert_eq!(
+            Fp::new(0x1234, 65521).unwrap().to_be_bytes(),
+            vec![0x12, 0x34]
+        );
+        assert_eq!(
+            Fp::new(0x12, 65521).unwrap().to_be_bytes(),
+            vec![0x00, 0x12]
+        );
+
+        let template = Fp::new(0, 65521).unwrap();
+        assert_eq!(
+            template.with_be_bytes(&[0x12, 0x34]),
+            Fp::new(0x1234, 65521)
+        );
+        assert_eq!(
+            template.with_be_bytes(&[0xff, 0xf1]),
+            Err(CryptoError::NotInRange {
+                num: "65521".to_string(),
+                prime: "65521".to_string()
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let element = Fp::new(3, PRIME).unwrap();
+        let json = serde_json::to_string(&element).unwrap();
+
+        assert_eq!(json, r#"{"num":3,"modulus":7}"#);
+        assert_eq!(serde_json::from_str::<Fp>(&json).unwrap(), element);
+
+        assert!(serde_json::from_str::<Fp>(r#"{"num":7,"modulus":7}"#).is_err());
+        assert!(serde_json::from_str::<Fp>(r#"{"num":0,"modulus":0}"#).is_err());
+    }
+
+    #[test]
+    fn test_field_element_new_reduced() {
+        assert_eq!(Fp::new_reduced(10, PRIME).num, 3);
+        assert_eq!(Fp::new_reduced(PRIME, PRIME).num, 0);
+        assert_eq!(
+            Fp::new_reduced(u32::MAX, PRIME_2),
+            Fp::new(u32::MAX as i64, PRIME_2).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus ZERO")]
+    fn test_field_element_new_reduced_zero_modulus() {
+        Fp::new_reduced(1, ZERO);
+    }
+
+    #[test]
+    fn test_field_element_try_from() {
+        assert_eq!(Fp::try_from((3, PRIME)), Fp::new(3, PRIME));
+        assert_eq!(
+            Fp::try_from((PRIME, PRIME)),
+            Err(CryptoError::NotInRange {
+                num: "7".to_string(),
+                prime: "7".to_string()
+            })
+        );
+        assert_eq!(Fp::try_from((0, ZERO)), Err(CryptoError::ZeroModulus));
+
+        let elements: Result<Vec<Fp>, CryptoError> = [(1, PRIME), (2, PRIME)]
+            .into_iter()
+            .map(Fp::try_from)
+            .collect();
+        assert_eq!(elements.unwrap().len(), 2);
+        let elements: Result<Vec<Fp>, CryptoError> = [(1, PRIME), (12, PRIME_2), (12, PRIME)]
+            .into_iter()
+            .map(Fp::try_from)
+            .collect();
+        assert!(elements.is_err());
+    }
+
+    #[test]
+    fn test_field_element_legendre() {
+        // squares in 𝔽7: {1, 2, 4}, in 𝔽11: {1, 3, 4, 5, 9}
+        let symbols = |prime: u32| -> Vec<i8> {
+            (0..prime)
+                .map(|num| Fp::new(num as i64, prime).unwrap().legendre())
+                .collect()
+        };
+
+        assert_eq!(symbols(PRIME), vec![0, 1, 1, -1, 1, -1, -1]);
+        assert_eq!(symbols(PRIME_2), vec![0, 1, -1, 1, 1, 1, -1, -1, -1, 1, -1]);
+    }
+
+    #[test]
+    fn test_field_element_is_quadratic_residue() {
+        for prime in [PRIME, PRIME_2, 13, 223] {
+            let squares: Vec<u32> = (0..prime).map(|num| num * num % prime).collect();
+
+            for num in 0..prime {
+                let element = Fp::new(num as i64, prime).unwrap();
+
+                assert_eq!(element.is_quadratic_residue(), squares.contains(&num));
+                assert_eq!(element.is_quadratic_residue(), element.sqrt().is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_element_nth_root() {
+        // gcd(3, 10) = 1, so cubing permutes 𝔽11
+        for num in 0..PRIME_2 {
+            let element = Fp::new(num as i64, PRIME_2).unwrap();
+            let root = element.nth_root(3).unwrap();
+
+            assert_eq!(root.pow(3), element);
+        }
+
+        let element = Fp::new(5, 17).unwrap();
+        assert_eq!(element.nth_root(5).unwrap().pow(5), element);
+        assert_eq!(element.nth_root(1), Ok(element));
+
+        // gcd(3, 6) = 3: 1 has the three cube roots 1, 2 and 4 in 𝔽7
+        assert_eq!(
+            Fp::new(1, PRIME).unwrap().nth_root(3),
+            Err(CryptoError::NoUniqueRoot {
+                n: 3,
+                modulus: PRIME
+            })
+        );
+    }
+
+    #[test]
+    fn test_field_element_ct_eq() {
+        let elements: Vec<Fp> = [PRIME, PRIME_2, 13]
+            .into_iter()
+            .flat_map(|prime| (0..prime).map(move |num| Fp::new(num as i64, prime).unwrap()))
+            .collect();
+
+        for a in &elements {
+            for b in &elements {
+                assert_eq!(a.ct_eq(b), a == b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_element_with_num() {
+        let a = Fp::new(3, PRIME).unwrap();
+
+        assert_eq!(a.with_num(5), Fp::new(5, PRIME));
+        assert_eq!(a.with_num(5).unwrap().modulus, a.modulus);
+        assert_eq!(a.with_num(0), Ok(a.zero()));
+        assert_eq!(
+            a.with_num(PRIME),
+            Err(CryptoError::NotInRange {
+                num: "7".to_string(),
+                prime: "7".to_string()
+            })
+        );
+
+        let small = Fp::new(1, 5).unwrap();
+        assert!(small.with_num(5).is_err());
+        assert!(small.with_num(4).is_ok());
+    }
+
+    #[test]
+    fn test_modpow() {
+        assert_eq!(modpow(2, 10, 1000), 24);
+        assert_eq!(modpow(3, 0, 7), 1);
+        assert_eq!(modpow(0, 0, 7), 1);
+        assert_eq!(modpow(5, 3, 1), 0);
+        assert_eq!(modpow(10, 1, 7), 3);
+        // Fermat: a^(p - 1) = 1 for the Mersenne prime 2⁶¹ - 1
+        assert_eq!(modpow(123_456_789, (1 << 61) - 2, (1 << 61) - 1), 1);
+        // (u64::MAX - 1)² ≡ 1 (mod u64::MAX), which overflows u64 on the way
+        assert_eq!(modpow(u64::MAX - 1, 2, u64::MAX), 1);
+    }
+
+    #[test]
+    fn test_pow_steps() {
+        let a = Fp::new(3, 31).unwrap();
+
+        // 5 = 0b101: 1, 3, 3², 3⁴, 3⁵
+        let expected: Vec<Fp> = [0, 1, 2, 4, 5].iter().map(|&e| a.pow(e)).collect();
+        assert_eq!(a.pow_steps(5), expected);
+
+        for exponent in (1..200).chain([u32::MAX]) {
+            let steps = a.pow_steps(exponent);
+            let bits = u32::BITS - exponent.leading_zeros();
+
+            assert_eq!(steps.len() as u32, bits + exponent.count_ones());
+            assert_eq!(steps.last(), Some(&a.pow(exponent)));
+        }
+
+        assert!(a.pow_steps(0).is_empty());
+    }
+
+    #[test]
+    // the references are what is being tested, `Copy` or not
+    #[allow(clippy::op_ref)]
+    fn test_reference_operators() {
+        let a = Fp::new(3, PRIME).unwrap();
+        let b = Fp::new(12, PRIME).unwrap();
+
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - &b, a - b);
+        assert_eq!(&a * &b, a * b);
+        assert_eq!(&a / &b, a / b);
+        assert_eq!(-&a, -a);
+    }
+}
+
+/*
+ * The field axioms over random primes and elements. Primes come either from a fixed list
+ * reaching up to 2³² (where u32 intermediates overflow) or as the next prime after a small
+ * random number, which keeps shrunk counterexamples small
+ */
+#[cfg(test)]
+mod field_axioms_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const LARGE_PRIMES: [u32; 4] = [65521, 65537, 2_147_483_647, 4_294_967_291];
+
+    fn is_prime(n: u32) -> bool {
+        n >= 2
+            && (2..)
+                .take_while(|d| d * d <= n)
+                .all(|d| !n.is_multiple_of(d))
+    }
+
+    fn next_prime(n: u32) -> u32 {
+        (n..).find(|&candidate| is_prime(candidate)).unwrap()
+    }
+
+    fn prime() -> impl Strategy<Value = u32> {
+        prop_oneof![
+            (2_u32..10_000).prop_map(next_prime),
+            prop::sample::select(&LARGE_PRIMES[..]),
+        ]
+    }
+
+    fn elements<const N: usize>() -> impl Strategy<Value = [Fp; N]> {
+        prime().prop_flat_map(|prime| {
+            prop::array::uniform::<_, N>(0..prime)
+                .prop_map(move |nums| nums.map(|num| Fp::new_reduced(num, prime)))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn addition_is_associative_and_commutative([a, b, c] in elements::<3>()) {
+            prop_assert_eq!((a + b) + c, a + (b + c));
+            prop_assert_eq!(a + b, b + a);
+        }
+
+        #[test]
+        fn multiplication_is_associative_and_commutative([a, b, c] in elements::<3>()) {
+            prop_assert_eq!((a * b) * c, a * (b * c));
+            prop_assert_eq!(a * b, b * a);
+        }
+
+        #[test]
+        fn multiplication_distributes_over_addition([a, b, c] in elements::<3>()) {
+            prop_assert_eq!(a * (b + c), a * b + a * c);
+        }
+
+        #[test]
+        fn identities([a] in elements::<1>()) {
+            prop_assert_eq!(a + a.zero(), a);
+            prop_assert_eq!(a * a.one(), a);
+            prop_assert_eq!(a * a.zero(), a.zero());
+        }
+
+        #[test]
+        fn additive_inverse([a, b] in elements::<2>()) {
+            prop_assert_eq!(a + a.add_inverse(), a.zero());
+            prop_assert_eq!(a.add_inverse(), -a);
+            prop_assert!(a.add_inverse().num < a.modulus);
+            prop_assert_eq!(a - b, a + -b);
+        }
+
+        #[test]
+        fn multiplicative_inverse([a, b] in elements::<2>()) {
+            prop_assume!(!a.is_zero());
+
+            prop_assert_eq!(a * a.mul_inverse(), a.one());
+            prop_assert_eq!(b / a * a, b);
+        }
+
+        #[test]
+        fn pow_is_repeated_multiplication([a] in elements::<1>(), exponent in 0_u32..64) {
+            let product = (0..exponent).fold(a.one(), |product, _| product * a);
+
+            prop_assert_eq!(a.pow(exponent), product);
+        }
+
+        #[test]
+        fn fermat([a] in elements::<1>()) {
+            // a^p = a for every a, including ZERO
+            prop_assert_eq!(a.pow(a.modulus), a);
+        }
+    }
 }
@@ -0,0 +1,254 @@
+// `Fp` with 64-bit elements: moduli up to 2⁶⁴ - 1 (primes up to ~1.8·10¹⁹) with the
+// products taken in u128, for fields too big for `Fp` that don't need the `bigint` feature
+
+use std::fmt;
+use std::ops;
+
+use crate::exercises::{ec_point::Field, error::CryptoError, finite_field::modpow};
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Fp64 {
+    pub num: u64,
+    pub modulus: u64,
+}
+
+impl Fp64 {
+    /*
+     * as `Fp::new`, `num` is reduced into [0, modulus), negatives included. A modulus of 0
+     * or 1 is an error: `mul_inverse` takes modulus - 2
+     */
+    pub fn new(num: i128, modulus: u64) -> Result<Self, CryptoError> {
+        if modulus == 0 {
+            return Err(CryptoError::ZeroModulus);
+        }
+        if modulus < 2 {
+            return Err(CryptoError::ModulusTooSmall(modulus));
+        }
+
+        Ok(Self {
+            num: num.rem_euclid(modulus as i128) as u64,
+            modulus,
+        })
+    }
+
+    // self^exponent for exponents past u32, the exponent is used as is (not reduced)
+    pub fn pow_u64(&self, exponent: u64) -> Self {
+        Self {
+            num: modpow(self.num, exponent, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+
+    pub fn safe_div(self, other: Self) -> Result<Self, CryptoError> {
+        if self.modulus != other.modulus {
+            return Err(CryptoError::DifferentFields {
+                operation: "divide",
+            });
+        }
+
+        if other.num == 0 {
+            return Err(CryptoError::DivByZero);
+        }
+
+        Ok(self / other)
+    }
+
+    fn with_value(self, num: u128) -> Self {
+        Self {
+            num: (num % self.modulus as u128) as u64,
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Field for Fp64 {
+    fn zero(&self) -> Self {
+        self.with_value(0)
+    }
+
+    fn one(&self) -> Self {
+        self.with_value(1)
+    }
+
+    // the exponent is used as is (not reduced)
+    fn pow(&self, exponent: u32) -> Self {
+        self.pow_u64(exponent as u64)
+    }
+
+    // Fermat's little theorem: a⁻¹ = a^(p - 2), which needs the modulus to be prime
+    fn mul_inverse(&self) -> Self {
+        self.pow_u64(self.modulus - 2)
+    }
+
+    fn add_inverse(&self) -> Self {
+        -*self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    fn scalar_mul(&self, by: u32) -> Self {
+        self.with_value(self.num as u128 * by as u128)
+    }
+}
+
+impl fmt::Display for Fp64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} — 𝔽{}", self.num, self.modulus)
+    }
+}
+
+impl ops::Add for Fp64 {
+    type Output = Self;
+
+    // assumes `rhs` has the same MODULUS
+    fn add(self, rhs: Self) -> Self::Output {
+        self.with_value(self.num as u128 + rhs.num as u128)
+    }
+}
+
+impl ops::Sub for Fp64 {
+    type Output = Self;
+
+    // assumes `rhs` has the same MODULUS
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.with_value(self.num as u128 + self.modulus as u128 - rhs.num as u128)
+    }
+}
+
+impl ops::Mul for Fp64 {
+    type Output = Self;
+
+    // assumes `rhs` has the same MODULUS
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.with_value(self.num as u128 * rhs.num as u128)
+    }
+}
+
+impl ops::Div for Fp64 {
+    type Output = Self;
+
+    // assumes `rhs` has the same MODULUS & is not ZERO
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.mul_inverse()
+    }
+}
+
+impl ops::Neg for Fp64 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.with_value(self.modulus as u128 - self.num as u128)
+    }
+}
+
+#[cfg(test)]
+mod fp64_tests {
+    use super::*;
+    use crate::exercises::{ec_point::Point, finite_field::Fp};
+
+    // the first prime after 2⁴⁰
+    const PRIME: u64 = 1_099_511_627_791;
+
+    fn fe(num: i128) -> Fp64 {
+        Fp64::new(num, PRIME).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(fe(-1).num, PRIME - 1);
+        assert_eq!(fe(PRIME as i128 + 5).num, 5);
+        assert_eq!(Fp64::new(1, 0), Err(CryptoError::ZeroModulus));
+        assert_eq!(Fp64::new(1, 1), Err(CryptoError::ModulusTooSmall(1)));
+        assert_eq!(Fp64::new(-1, 2).map(|a| a.num), Ok(1));
+    }
+
+    #[test]
+    fn test_mul() {
+        let a = fe(987_654_321_012);
+        let b = fe(123_456_789_098);
+
+        assert_eq!(
+            (a * b).num as u128,
+            987_654_321_012 * 123_456_789_098 % PRIME as u128
+        );
+        assert_eq!(fe(-1) * fe(-1), fe(1));
+        assert_eq!(a.scalar_mul(u32::MAX), a * fe(u32::MAX as i128));
+    }
+
+    #[test]
+    fn test_pow() {
+        let a = fe(987_654_321_012);
+
+        // Fermat: a^(p - 1) = 1
+        assert_eq!(a.pow_u64(PRIME - 1), fe(1));
+        assert_eq!(a.pow_u64(PRIME), a);
+        assert_eq!(a.pow(3), a * a * a);
+        assert_eq!(a.pow(0), fe(1));
+    }
+
+    #[test]
+    fn test_inverse() {
+        for num in [1, 2, 3, 987_654_321_012, PRIME as i128 - 1] {
+            let a = fe(num);
+
+            assert_eq!(a * a.mul_inverse(), fe(1));
+            assert_eq!(fe(7) / a * a, fe(7));
+        }
+
+        assert_eq!(fe(7).safe_div(fe(0)), Err(CryptoError::DivByZero));
+    }
+
+    #[test]
+    fn test_matches_fp() {
+        // same answers as `Fp` where both apply
+        let prime = 223;
+        let (a, b) = (Fp::new(47, prime).unwrap(), Fp::new(190, prime).unwrap());
+        let (c, d) = (Fp64::new(47, 223).unwrap(), Fp64::new(190, 223).unwrap());
+
+        for (fp, fp64) in [
+            (a + b, c + d),
+            (a - b, c - d),
+            (b - a, d - c),
+            (a * b, c * d),
+            (a / b, c / d),
+            (-a, -c),
+            (a.pow(100), c.pow(100)),
+            (a.mul_inverse(), c.mul_inverse()),
+        ] {
+            assert_eq!(fp.num as u64, fp64.num);
+        }
+    }
+
+    #[test]
+    fn test_largest_modulus() {
+        // the largest 64-bit prime, whose products need all of u128
+        let prime = u64::MAX - 58;
+        let a = Fp64::new(-2, prime).unwrap();
+
+        assert_eq!(a * a, Fp64::new(4, prime).unwrap());
+        assert_eq!(a + a, Fp64::new(-4, prime).unwrap());
+        assert_eq!(a * a.mul_inverse(), a.one());
+    }
+
+    #[test]
+    fn test_curve_over_fp64() {
+        // y² = x³ + 7 over the 40-bit field; p ≡ 3 (mod 4), so y = rhs^((p + 1) / 4)
+        let (a, b) = (fe(0), fe(7));
+        let point = (1..)
+            .find_map(|x| {
+                let rhs = fe(x).pow(3) + b;
+                let y = rhs.pow_u64((PRIME + 1) / 4);
+
+                Point::new(a, b, Some(fe(x)), Some(y)).ok()
+            })
+            .unwrap();
+
+        let doubled = (point + point).unwrap();
+        assert_eq!(point.scalar_mul(2), doubled);
+        assert_eq!(point.scalar_mul(3), (doubled + point).unwrap());
+        assert_eq!(point.scalar_mul(1000), point.scalar_mul_ladder(1000));
+    }
+}
@@ -0,0 +1,123 @@
+// Book: Programming Bitcoin: Learn How to Program Bitcoin from Scratch by Jimmy song
+// Chapter 4: Serialization — hash functions
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+/*
+ * SHA-256 applied twice, used for checksums, signature hashes and block ids
+ */
+pub fn hash256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+/*
+ * RIPEMD-160 of the SHA-256, turns a SEC public key into the 20 bytes of an address
+ */
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(Sha256::digest(data)).into()
+}
+
+/*
+ * BIP-340 tagged hash: SHA256(SHA256(tag) || SHA256(tag) || msg), a different hash
+ * function per tag so a hash computed for one purpose can't be replayed for another
+ *
+ * The prefix is exactly one 64-byte SHA-256 block, so the hasher state after it (the
+ * midstate) is cached per tag and each call only hashes `msg`
+ */
+pub fn tagged_hash(tag: &str, msg: &[u8]) -> [u8; 32] {
+    thread_local! {
+        static MIDSTATES: RefCell<HashMap<String, Sha256>> = RefCell::new(HashMap::new());
+    }
+
+    let mut hasher = MIDSTATES.with(|midstates| {
+        midstates
+            .borrow_mut()
+            .entry(tag.to_string())
+            .or_insert_with(|| {
+                let tag_hash = Sha256::digest(tag.as_bytes());
+                Sha256::new().chain_update(tag_hash).chain_update(tag_hash)
+            })
+            .clone()
+    });
+
+    hasher.update(msg);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod hashes_tests {
+    use super::*;
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn test_hash256() {
+        assert_eq!(
+            to_hex(&hash256(b"hello")),
+            "9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50"
+        );
+        assert_eq!(
+            to_hex(&hash256(b"")),
+            "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456"
+        );
+        // the message hash z signed in chapter 3
+        assert_eq!(
+            to_hex(&hash256(b"Programming Bitcoin!")),
+            "969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48"
+        );
+    }
+
+    #[test]
+    fn test_hash160() {
+        // compressed SEC of the secp256k1 generator, i.e. the public key of secret 1
+        let sec = [
+            0x02, 0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce,
+            0x87, 0x0b, 0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81,
+            0x5b, 0x16, 0xf8, 0x17, 0x98,
+        ];
+
+        assert_eq!(
+            to_hex(&hash160(&sec)),
+            "751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+    }
+
+    #[test]
+    fn test_tagged_hash() {
+        let vectors: [(&str, &[u8], &str); 4] = [
+            (
+                "BIP0340/challenge",
+                b"",
+                "c216d352f5818b7b4beacd4ae0a26fe888080823d2a598856661bcd54f1b3713",
+            ),
+            (
+                "BIP0340/aux",
+                &[0; 32],
+                "54f169cfc9e2e5727480441f90ba25c488f461c70b5ea5dcaaf7af69270aa514",
+            ),
+            (
+                "BIP0340/nonce",
+                b"abc",
+                "000beb2c0c2b6cebe3a7e17e283816c2cd4a02e148ea65e9f218d8007d35de4b",
+            ),
+            (
+                "TapLeaf",
+                &[0xc0, 0x01, 0x51],
+                "a85b2107f791b26a84e7586c28cec7cb61202ed3d01944d832500f363782d675",
+            ),
+        ];
+
+        // twice, the second pass goes through the cached midstates
+        for _ in 0..2 {
+            for (tag, msg, expected) in vectors {
+                assert_eq!(to_hex(&tagged_hash(tag, msg)), expected);
+            }
+        }
+    }
+}
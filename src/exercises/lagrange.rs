@@ -0,0 +1,110 @@
+// Lagrange interpolation: the value at any x of the unique polynomial of degree < k through
+// k points with distinct x-coordinates, without working out its coefficients
+
+use crate::exercises::finite_field::Fp;
+
+/*
+ * L(x) = Σ yᵢ · Π_{j≠i} (x - xⱼ) / (xᵢ - xⱼ)
+ *
+ * Each product is 1 at xᵢ and 0 at every other xⱼ, so L goes through all the points.
+ * Every coordinate and `x` have to be in the same field, and no two xᵢ may be equal
+ */
+pub fn lagrange_interpolate(points: &[(Fp, Fp)], x: Fp) -> Result<Fp, String> {
+    if points.is_empty() {
+        return Err("cannot interpolate through no points".to_string());
+    }
+    if points
+        .iter()
+        .any(|(x_i, y_i)| x_i.modulus != x.modulus || y_i.modulus != x.modulus)
+    {
+        return Err("points are in different fields".to_string());
+    }
+    for (i, (x_i, _)) in points.iter().enumerate() {
+        if points[..i].iter().any(|(x_j, _)| x_j == x_i) {
+            return Err(format!("x = {} appears more than once", x_i.num));
+        }
+    }
+
+    let zero = Fp::new_reduced(0, x.modulus);
+    let one = Fp::new_reduced(1, x.modulus);
+
+    let value = points
+        .iter()
+        .enumerate()
+        .fold(zero, |sum, (i, &(x_i, y_i))| {
+            let basis = points
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(one, |product, (_, &(x_j, _))| {
+                    product * (x - x_j) / (x_i - x_j)
+                });
+
+            sum + y_i * basis
+        });
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod lagrange_tests {
+    use super::*;
+
+    const PRIME: u32 = 97;
+
+    fn fe(num: i64) -> Fp {
+        Fp::new(num, PRIME).unwrap()
+    }
+
+    // f(x) = 3x³ - 5x + 11
+    fn f(x: i64) -> Fp {
+        fe(3 * x * x * x - 5 * x + 11)
+    }
+
+    #[test]
+    fn test_interpolate_cubic() {
+        let points: Vec<(Fp, Fp)> = [2, 5, 13, 40].iter().map(|&x| (fe(x), f(x))).collect();
+
+        for x in [0, 1, 2, 7, 50, 96] {
+            assert_eq!(lagrange_interpolate(&points, fe(x)), Ok(f(x)));
+        }
+        // the constant term
+        assert_eq!(lagrange_interpolate(&points, fe(0)), Ok(fe(11)));
+
+        // a fifth point on the same cubic changes nothing
+        let mut more = points.clone();
+        more.push((fe(60), f(60)));
+        assert_eq!(lagrange_interpolate(&more, fe(0)), Ok(fe(11)));
+
+        // three of them define a different (quadratic) polynomial
+        assert_ne!(lagrange_interpolate(&points[..3], fe(0)), Ok(fe(11)));
+    }
+
+    #[test]
+    fn test_interpolate_single_point() {
+        // degree 0: the constant through the point
+        for x in [0, 1, 42] {
+            assert_eq!(lagrange_interpolate(&[(fe(3), fe(8))], fe(x)), Ok(fe(8)));
+        }
+    }
+
+    #[test]
+    fn test_interpolate_errors() {
+        assert_eq!(
+            lagrange_interpolate(&[], fe(0)),
+            Err("cannot interpolate through no points".to_string())
+        );
+        assert_eq!(
+            lagrange_interpolate(&[(fe(1), fe(2))], Fp::new(0, 89).unwrap()),
+            Err("points are in different fields".to_string())
+        );
+        assert_eq!(
+            lagrange_interpolate(&[(fe(1), Fp::new(2, 89).unwrap())], fe(0)),
+            Err("points are in different fields".to_string())
+        );
+        assert_eq!(
+            lagrange_interpolate(&[(fe(1), fe(2)), (fe(4), fe(5)), (fe(98), fe(3))], fe(0)),
+            Err("x = 1 appears more than once".to_string())
+        );
+    }
+}
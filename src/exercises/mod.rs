@@ -1,2 +1,29 @@
+pub mod addition_table;
+pub mod barrett;
+pub mod base58;
+pub mod bech32;
 pub mod ec_point;
+#[cfg(feature = "bigint")]
+pub mod ecdsa;
+pub mod error;
+#[cfg(feature = "bigint")]
+mod field256;
 pub mod finite_field;
+pub mod fp64;
+pub mod hashes;
+pub mod lagrange;
+pub mod mont_field;
+#[cfg(feature = "bigint")]
+pub mod montgomery;
+pub mod num;
+#[cfg(feature = "bigint")]
+pub mod order;
+#[cfg(feature = "bigint")]
+pub mod p256;
+pub mod polynomial;
+#[cfg(feature = "bigint")]
+pub mod s256;
+#[cfg(feature = "bigint")]
+pub mod schnorr;
+pub mod shamir;
+pub mod varint;
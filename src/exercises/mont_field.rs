@@ -0,0 +1,285 @@
+// Montgomery form: x is stored as x·R mod p (R = 2³²) so that products can be reduced
+// with REDC, a multiplication, a mask and a shift, instead of a division by p
+//
+// Not to be confused with the Montgomery *curves* in `montgomery`
+
+use std::fmt;
+use std::ops;
+
+use crate::exercises::{ec_point::Field, error::CryptoError, finite_field::Fp};
+
+/*
+ * An odd prime p < 2³² with the two constants REDC needs:
+ * p' = -p⁻¹ (mod R) and R² mod p, the latter to bring values into Montgomery form
+ */
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct MontField {
+    prime: u32,
+    neg_inverse: u32,
+    r_squared: u32,
+}
+
+/*
+ * An element of a `MontField`, `value` being x·R mod p for the element x
+ */
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct MontFp {
+    value: u32,
+    pub field: MontField,
+}
+
+impl MontField {
+    // R = 2³² has to be invertible mod p, hence p odd; p = 1 is odd but {0} is no field
+    pub fn new(prime: u32) -> Result<Self, CryptoError> {
+        if prime == 0 {
+            return Err(CryptoError::ZeroModulus);
+        }
+        if prime < 2 {
+            return Err(CryptoError::ModulusTooSmall(prime.into()));
+        }
+        if prime.is_multiple_of(2) {
+            return Err(CryptoError::EvenModulus(prime));
+        }
+
+        // Newton's iteration, each step doubles the number of correct low bits of p⁻¹
+        let mut inverse: u32 = prime;
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2_u32.wrapping_sub(prime.wrapping_mul(inverse)));
+        }
+
+        let r_mod_p = (u32::MAX as u64 % prime as u64 + 1) % prime as u64;
+
+        Ok(Self {
+            prime,
+            neg_inverse: inverse.wrapping_neg(),
+            r_squared: (r_mod_p * r_mod_p % prime as u64) as u32,
+        })
+    }
+
+    pub fn prime(&self) -> u32 {
+        self.prime
+    }
+
+    /*
+     * REDC(t) = t·R⁻¹ (mod p) for t < p·R:
+     * m = (t mod R)·p' mod R makes t + m·p divisible by R, and (t + m·p)/R < 2p
+     */
+    pub fn redc(&self, t: u64) -> u32 {
+        let m = (t as u32).wrapping_mul(self.neg_inverse);
+        let reduced = ((t as u128 + m as u128 * self.prime as u128) >> 32) as u64;
+
+        if reduced >= self.prime as u64 {
+            (reduced - self.prime as u64) as u32
+        } else {
+            reduced as u32
+        }
+    }
+
+    // x ↦ x·R mod p, as REDC(x·R²)
+    pub fn from_normal(&self, num: u32) -> MontFp {
+        MontFp {
+            value: self.redc((num % self.prime) as u64 * self.r_squared as u64),
+            field: *self,
+        }
+    }
+}
+
+impl MontFp {
+    // x·R ↦ x, as REDC(x·R)
+    pub fn to_normal(self) -> u32 {
+        self.field.redc(self.value as u64)
+    }
+
+    pub fn from_fp(element: Fp) -> Result<Self, CryptoError> {
+        Ok(MontField::new(element.modulus)?.from_normal(element.num))
+    }
+
+    pub fn to_fp(self) -> Fp {
+        Fp {
+            num: self.to_normal(),
+            modulus: self.field.prime,
+        }
+    }
+
+    fn with_value(self, value: u32) -> Self {
+        Self {
+            value,
+            field: self.field,
+        }
+    }
+}
+
+impl Field for MontFp {
+    fn zero(&self) -> Self {
+        self.with_value(0)
+    }
+
+    fn one(&self) -> Self {
+        self.field.from_normal(1)
+    }
+
+    // square and multiply, the exponent is used as is (not reduced)
+    fn pow(&self, exponent: u32) -> Self {
+        let mut result = self.one();
+        let mut base = *self;
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    // Fermat's little theorem: a⁻¹ = a^(p - 2)
+    fn mul_inverse(&self) -> Self {
+        self.pow(self.field.prime - 2)
+    }
+
+    // -(x·R) = (-x)·R, the form is kept as is
+    fn add_inverse(&self) -> Self {
+        self.zero() - *self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+
+    fn scalar_mul(&self, by: u32) -> Self {
+        *self * self.field.from_normal(by)
+    }
+}
+
+impl fmt::Display for MontFp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} — 𝔽{}", self.to_normal(), self.field.prime)
+    }
+}
+
+impl ops::Add for MontFp {
+    type Output = Self;
+
+    // assumes `rhs` is in the same field; x·R + y·R = (x + y)·R
+    fn add(self, rhs: Self) -> Self::Output {
+        let sum = self.value as u64 + rhs.value as u64;
+        let prime = self.field.prime as u64;
+
+        self.with_value(if sum >= prime { sum - prime } else { sum } as u32)
+    }
+}
+
+impl ops::Sub for MontFp {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.value >= rhs.value {
+            self.with_value(self.value - rhs.value)
+        } else {
+            self.with_value((self.value as u64 + self.field.prime as u64 - rhs.value as u64) as u32)
+        }
+    }
+}
+
+impl ops::Mul for MontFp {
+    type Output = Self;
+
+    // REDC(x·R · y·R) = x·y·R
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.with_value(self.field.redc(self.value as u64 * rhs.value as u64))
+    }
+}
+
+impl ops::Div for MontFp {
+    type Output = Self;
+
+    // assumes `rhs` is not ZERO
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.mul_inverse()
+    }
+}
+
+impl ops::Neg for MontFp {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.add_inverse()
+    }
+}
+
+#[cfg(test)]
+mod mont_field_tests {
+    use super::*;
+
+    const PRIMES: [u32; 6] = [3, 7, 223, 65521, 2_147_483_647, 4_294_967_291];
+
+    // xorshift64
+    fn samples(seed: u64, count: usize) -> impl Iterator<Item = u64> {
+        let mut state = seed;
+
+        (0..count).map(move |_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        })
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for prime in PRIMES {
+            let field = MontField::new(prime).unwrap();
+
+            for num in samples(prime as u64, 1000).map(|x| (x % prime as u64) as u32) {
+                assert_eq!(field.from_normal(num).to_normal(), num);
+            }
+            assert_eq!(field.from_normal(prime - 1).to_normal(), prime - 1);
+            assert_eq!(field.from_normal(0).to_normal(), 0);
+        }
+    }
+
+    #[test]
+    fn test_mul_matches_naive() {
+        for prime in PRIMES {
+            let field = MontField::new(prime).unwrap();
+            let reduce = |x: u64| (x % prime as u64) as u32;
+
+            for (a, b) in samples(1, 1000).zip(samples(2, 1000)) {
+                let (a, b) = (reduce(a), reduce(b));
+                let product = field.from_normal(a) * field.from_normal(b);
+
+                assert_eq!(product.to_normal(), reduce(a as u64 * b as u64));
+                assert_eq!(
+                    (field.from_normal(a) + field.from_normal(b)).to_normal(),
+                    reduce(a as u64 + b as u64)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_matches_fp() {
+        let prime = 223;
+
+        for num in 1..prime {
+            let fp = Fp::new(num as i64, prime).unwrap();
+            let mont = MontFp::from_fp(fp).unwrap();
+
+            assert_eq!(mont.pow(5).to_fp(), fp.pow(5));
+            assert_eq!(mont.mul_inverse().to_fp(), fp.mul_inverse());
+            assert_eq!((-mont).to_fp(), -fp);
+            assert_eq!((mont - mont.one()).to_fp(), fp - fp.one());
+        }
+    }
+
+    #[test]
+    fn test_invalid_modulus() {
+        assert_eq!(MontField::new(0), Err(CryptoError::ZeroModulus));
+        assert_eq!(MontField::new(1), Err(CryptoError::ModulusTooSmall(1)));
+        assert_eq!(MontField::new(2), Err(CryptoError::EvenModulus(2)));
+    }
+}
@@ -0,0 +1,398 @@
+// RFC 7748: Montgomery curves and X25519
+//
+// Curves of the form B·v² = u³ + A·u² + u, whose x-only ("u-coordinate") ladder makes a
+// simple and regular scalar multiplication for Diffie-Hellman
+
+use std::fmt;
+use std::ops;
+
+use num_bigint::BigUint;
+
+use crate::exercises::{ec_point::Field, error::CryptoError};
+
+/*
+ * B·v² = u³ + A·u² + u over some field, e.g. Curve25519: A = 486662, B = 1 over
+ * 𝔽(2²⁵⁵ - 19)
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct MontgomeryCurve<F> {
+    pub a: F,
+    pub b: F,
+}
+
+/*
+ * An affine point on a Montgomery curve, `u` and `v` being `None` is the point at
+ * infinity. The ladder only ever needs `u`
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct MontgomeryPoint<F> {
+    pub u: Option<F>,
+    pub v: Option<F>,
+}
+
+/*
+ * An element of 𝔽p, p = 2²⁵⁵ - 19, the base field of Curve25519
+ */
+#[derive(PartialEq, Debug, Clone)]
+pub struct Field25519 {
+    pub num: BigUint,
+}
+
+impl<F: Field> MontgomeryCurve<F> {
+    pub fn new(a: F, b: F) -> Self {
+        Self { a, b }
+    }
+
+    pub fn point(&self, u: F, v: F) -> Result<MontgomeryPoint<F>, CryptoError> {
+        let lhs = self.b.clone() * v.pow(2);
+        let rhs = u.pow(3) + self.a.clone() * u.pow(2) + u.clone();
+
+        if lhs != rhs {
+            return Err(CryptoError::NotOnCurve {
+                x: u.to_string(),
+                y: v.to_string(),
+                lhs: lhs.to_string(),
+                rhs: rhs.to_string(),
+            });
+        }
+
+        Ok(MontgomeryPoint {
+            u: Some(u),
+            v: Some(v),
+        })
+    }
+
+    /*
+     * The u-coordinate of k·P given only the u-coordinate of P, `scalar` is read as a
+     * little-endian integer and its lowest `bits` bits are used, so it needs at least
+     * ⌈bits/8⌉ bytes
+     *
+     * Each step does the same differential addition and doubling whatever the bit is,
+     * the bit only decides which of (x₂ : z₂), (x₃ : z₃) plays which role. That is an
+     * arithmetic conditional swap (see `cswap`) rather than a branch on the bit; the
+     * field arithmetic underneath is not constant-time though, so neither is the ladder.
+     * k·P = P(∞) comes out as 0
+     */
+    pub fn ladder(&self, scalar: &[u8], bits: usize, u: F) -> Result<F, CryptoError> {
+        if bits > scalar.len() * 8 {
+            return Err(CryptoError::ScalarTooShort {
+                bits,
+                length: scalar.len(),
+            });
+        }
+
+        let one = u.one();
+        let four = one.scalar_mul(4);
+        let a24 = (self.a.clone() - one.scalar_mul(2)) / four;
+
+        let x_1 = u.clone();
+        let (mut x_2, mut z_2) = (one.clone(), u.zero());
+        let (mut x_3, mut z_3) = (u, one);
+        let mut swap = 0;
+
+        for t in (0..bits).rev() {
+            let k_t = ((scalar[t / 8] >> (t % 8)) & 1) as u32;
+
+            swap ^= k_t;
+            cswap(swap, &mut x_2, &mut x_3);
+            cswap(swap, &mut z_2, &mut z_3);
+            swap = k_t;
+
+            let a = x_2.clone() + z_2.clone();
+            let aa = a.pow(2);
+            let b = x_2 - z_2;
+            let bb = b.pow(2);
+            let e = aa.clone() - bb.clone();
+            let c = x_3.clone() + z_3.clone();
+            let d = x_3 - z_3;
+            let da = d * a;
+            let cb = c * b;
+
+            x_3 = (da.clone() + cb.clone()).pow(2);
+            z_3 = x_1.clone() * (da - cb).pow(2);
+            x_2 = aa.clone() * bb;
+            z_2 = e.clone() * (aa + a24.clone() * e);
+        }
+
+        cswap(swap, &mut x_2, &mut x_3);
+        cswap(swap, &mut z_2, &mut z_3);
+
+        // z₂ = 0 is P(∞), whose inverse by Fermat is 0 as well
+        Ok(x_2 * z_2.mul_inverse())
+    }
+}
+
+/*
+ * RFC 7748's cswap with the mask replaced by field arithmetic: d = swap·(y - x), then
+ * x + d and y - d, which exchanges x and y for swap = 1 and leaves them for swap = 0
+ * without branching on `swap`
+ */
+fn cswap<F: Field>(swap: u32, x: &mut F, y: &mut F) {
+    let d = (y.clone() - x.clone()).scalar_mul(swap);
+
+    *x = x.clone() + d.clone();
+    *y = y.clone() - d;
+}
+
+impl Field25519 {
+    pub fn new(num: BigUint) -> Self {
+        Self {
+            num: num % Self::prime(),
+        }
+    }
+
+    pub fn prime() -> BigUint {
+        (BigUint::from(1_u32) << 255_u32) - BigUint::from(19_u32)
+    }
+
+    // 32 little-endian bytes, the top bit is ignored (RFC 7748 §5)
+    pub fn from_le_bytes(bytes: &[u8; 32]) -> Self {
+        let mut masked = *bytes;
+        masked[31] &= 0x7f;
+
+        Self::new(BigUint::from_bytes_le(&masked))
+    }
+
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0_u8; 32];
+        let digits = self.num.to_bytes_le();
+        bytes[..digits.len()].copy_from_slice(&digits);
+
+        bytes
+    }
+}
+
+impl MontgomeryCurve<Field25519> {
+    // Curve25519: v² = u³ + 486662·u² + u
+    pub fn curve25519() -> Self {
+        Self::new(
+            Field25519::new(BigUint::from(486662_u32)),
+            Field25519::new(BigUint::from(1_u32)),
+        )
+    }
+}
+
+/*
+ * X25519(k, u): the scalar is "clamped" first, cleared low bits make it a multiple of the
+ * cofactor 8 and the fixed top bit makes the ladder length independent of the key
+ */
+pub fn x25519(scalar: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    let mut k = scalar;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+
+    MontgomeryCurve::curve25519()
+        .ladder(&k, 255, Field25519::from_le_bytes(&u))
+        .expect("255 bits fit in 32 bytes")
+        .to_le_bytes()
+}
+
+impl Field for Field25519 {
+    fn zero(&self) -> Self {
+        Self::new(BigUint::from(0_u32))
+    }
+
+    fn one(&self) -> Self {
+        Self::new(BigUint::from(1_u32))
+    }
+
+    fn pow(&self, exponent: u32) -> Self {
+        Self::new(self.num.modpow(&BigUint::from(exponent), &Self::prime()))
+    }
+
+    // Fermat's little theorem: a⁻¹ = a^(p - 2)
+    fn mul_inverse(&self) -> Self {
+        let prime = Self::prime();
+        let exponent = &prime - BigUint::from(2_u32);
+
+        Self::new(self.num.modpow(&exponent, &prime))
+    }
+
+    fn add_inverse(&self) -> Self {
+        Self::new(Self::prime() - &self.num)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num == BigUint::from(0_u32)
+    }
+
+    fn scalar_mul(&self, by: u32) -> Self {
+        Self::new(&self.num * by)
+    }
+}
+
+impl fmt::Display for Field25519 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:064x}", self.num)
+    }
+}
+
+impl ops::Add for Field25519 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.num + rhs.num)
+    }
+}
+
+impl ops::Sub for Field25519 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.num + Self::prime() - rhs.num)
+    }
+}
+
+impl ops::Mul for Field25519 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.num * rhs.num)
+    }
+}
+
+impl ops::Div for Field25519 {
+    type Output = Self;
+
+    // assumes `rhs` is not ZERO
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.mul_inverse()
+    }
+}
+
+impl ops::Neg for Field25519 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.add_inverse()
+    }
+}
+
+#[cfg(test)]
+mod montgomery_tests {
+    use super::*;
+    use crate::exercises::finite_field::Fp;
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        let mut bytes = [0_u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+
+        bytes
+    }
+
+    #[test]
+    fn test_x25519_vectors() {
+        // RFC 7748 §5.2
+        let vectors = [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ];
+
+        for (scalar, u, expected) in vectors {
+            assert_eq!(x25519(from_hex(scalar), from_hex(u)), from_hex(expected));
+        }
+    }
+
+    #[test]
+    fn test_x25519_first_iteration() {
+        // RFC 7748 §5.2, k = u = 9 then (k, u) = (X25519(k, u), k), after one iteration
+        let mut nine = [0_u8; 32];
+        nine[0] = 9;
+
+        assert_eq!(
+            x25519(nine, nine),
+            from_hex("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+        );
+    }
+
+    #[test]
+    fn test_x25519_diffie_hellman() {
+        // RFC 7748 §6.1
+        let alice = from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = from_hex("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+        let mut base = [0_u8; 32];
+        base[0] = 9;
+
+        let alice_public = x25519(alice, base);
+        let bob_public = x25519(bob, base);
+
+        assert_eq!(
+            alice_public,
+            from_hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            from_hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+        assert_eq!(x25519(alice, bob_public), x25519(bob, alice_public));
+        assert_eq!(
+            x25519(alice, bob_public),
+            from_hex("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742")
+        );
+    }
+
+    #[test]
+    fn test_curve25519_base_point() {
+        // u = 9 and the v from RFC 7748 §4.1
+        let v = BigUint::parse_bytes(
+            b"14781619447589544791020593568409986887264606134616475288964881837755586237401",
+            10,
+        )
+        .unwrap();
+        let curve = MontgomeryCurve::curve25519();
+
+        assert!(curve
+            .point(
+                Field25519::new(BigUint::from(9_u32)),
+                Field25519::new(v.clone())
+            )
+            .is_ok());
+        assert!(matches!(
+            curve.point(
+                Field25519::new(BigUint::from(9_u32)),
+                Field25519::new(v + 1_u32)
+            ),
+            Err(CryptoError::NotOnCurve { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ladder_small_field() {
+        // v² = u³ + 3u² + u over 𝔽101: the ladder has to agree with adding u = 2 twice
+        // through the x-only doubling formula u(2P) = (u² - 1)² / (4u(u² + Au + 1))
+        let prime = 101;
+        let curve = MontgomeryCurve::new(Fp::new(3, prime).unwrap(), Fp::new(1, prime).unwrap());
+        let u = Fp::new(2, prime).unwrap();
+
+        let doubled = (u * u - Fp::new(1, prime).unwrap()).pow(2)
+            / (Fp::new(4, prime).unwrap() * u * (u * u + curve.a * u + Fp::new(1, prime).unwrap()));
+
+        assert_eq!(curve.ladder(&[2], 8, u), Ok(doubled));
+        assert_eq!(curve.ladder(&[1], 8, u), Ok(u));
+        assert!(curve.ladder(&[0], 8, u).unwrap().is_zero());
+
+        // the scalar has to have the bits the ladder is asked to walk
+        assert_eq!(curve.ladder(&[2, 0], 16, u), Ok(doubled));
+        assert_eq!(
+            curve.ladder(&[2], 9, u),
+            Err(CryptoError::ScalarTooShort { bits: 9, length: 1 })
+        );
+        assert_eq!(
+            curve.ladder(&[], 1, u),
+            Err(CryptoError::ScalarTooShort { bits: 1, length: 0 })
+        );
+        assert_eq!(curve.ladder(&[], 0, u), Ok(Fp::new(0, prime).unwrap()));
+    }
+}
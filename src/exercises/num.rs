@@ -0,0 +1,83 @@
+// Integer helpers on plain u64s, for moduli that are not (or not yet known to be) prime
+
+/*
+ * Greatest common divisor by Euclid's algorithm, gcd(a, 0) = a
+ */
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        return a;
+    }
+
+    gcd(b, a % b)
+}
+
+/*
+ * The x in [0, m) with a·x ≡ 1 (mod m), by the extended Euclidean algorithm: it tracks
+ * the coefficient s with rᵢ ≡ sᵢ·a (mod m), so when the remainders reach gcd(a, m) = 1
+ * the coefficient is the inverse. `None` when gcd(a, m) ≠ 1 (or m = 0), in which case
+ * there is no inverse. Unlike a^(m - 2) this does not need m to be prime
+ */
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+
+    let (mut r0, mut r1) = (m as i128, (a % m) as i128);
+    let (mut s0, mut s1) = (0_i128, 1_i128);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+
+    if r0 != 1 {
+        // m = 1 ends at r0 = 1 too, everything is ≡ 0 there, ZERO included
+        return None;
+    }
+
+    Some(s0.rem_euclid(m as i128) as u64)
+}
+
+#[cfg(test)]
+mod num_tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(18, 12), 6);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(gcd(0, 9), 9);
+        assert_eq!(gcd(9, 0), 9);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(u64::MAX, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 11), Some(10));
+        assert_eq!(mod_inverse(14, 11), Some(4));
+        // composite moduli work as long as a is coprime to them
+        assert_eq!(mod_inverse(7, 40), Some(23));
+        assert_eq!(mod_inverse(u64::MAX - 1, u64::MAX), Some(u64::MAX - 1));
+
+        assert_eq!(mod_inverse(2, 4), None);
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(0, 11), None);
+        assert_eq!(mod_inverse(3, 0), None);
+    }
+
+    #[test]
+    fn test_mod_inverse_is_inverse() {
+        let m = 1_000_u64;
+
+        for a in 0..m {
+            match mod_inverse(a, m) {
+                Some(x) => assert_eq!(a * x % m, 1),
+                None => assert_ne!(gcd(a, m), 1),
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// Scalars modulo the group order n
+//
+// Private keys, nonces and signature components live modulo n, point coordinates modulo
+// the field prime p. Keeping them in different types makes mixing the two a type error
+// rather than a wrong signature
+
+use std::fmt;
+use std::ops;
+
+use num_bigint::BigUint;
+
+use crate::exercises::{
+    error::CryptoError,
+    s256::{S256Field, S256Point},
+};
+
+/*
+ * An integer modulo `order`, the order n of some group (for secp256k1 the order of G,
+ * which is prime)
+ */
+#[derive(PartialEq, Debug, Clone)]
+pub struct OrderScalar {
+    pub num: BigUint,
+    pub order: BigUint,
+}
+
+impl OrderScalar {
+    pub fn new(num: BigUint, order: BigUint) -> Self {
+        Self {
+            num: num % &order,
+            order,
+        }
+    }
+
+    // modulo the order of the secp256k1 generator
+    pub fn secp256k1(num: BigUint) -> Self {
+        Self::new(num, S256Point::order())
+    }
+
+    /*
+     * A point's x-coordinate as a scalar, x mod n, as ECDSA does for r. The conversion
+     * loses information (p > n), which is why it is explicit
+     */
+    pub fn from_field(x: &S256Field) -> Self {
+        Self::secp256k1(x.num.clone())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == BigUint::from(0_u32)
+    }
+
+    /*
+     * Fermat's little theorem: a⁻¹ = a^(n - 2), which needs n prime. Zero has no
+     * inverse
+     */
+    pub fn inverse(&self) -> Result<Self, CryptoError> {
+        if self.is_zero() {
+            return Err(CryptoError::DivByZero);
+        }
+
+        let exponent = &self.order - BigUint::from(2_u32);
+
+        Ok(Self::new(
+            self.num.modpow(&exponent, &self.order),
+            self.order.clone(),
+        ))
+    }
+}
+
+impl fmt::Display for OrderScalar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:x} (mod n)", self.num)
+    }
+}
+
+impl ops::Add for OrderScalar {
+    type Output = Self;
+
+    // assumes `rhs` has the same ORDER
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.num + rhs.num, self.order)
+    }
+}
+
+impl ops::Sub for OrderScalar {
+    type Output = Self;
+
+    // assumes `rhs` has the same ORDER
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.num + &self.order - rhs.num, self.order)
+    }
+}
+
+impl ops::Mul for OrderScalar {
+    type Output = Self;
+
+    // assumes `rhs` has the same ORDER
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.num * rhs.num, self.order)
+    }
+}
+
+impl ops::Neg for OrderScalar {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(&self.order - self.num, self.order)
+    }
+}
+
+#[cfg(test)]
+mod order_tests {
+    use super::*;
+    use crate::exercises::ec_point::Field;
+
+    #[test]
+    fn test_inverse() {
+        let one = OrderScalar::secp256k1(BigUint::from(1_u32));
+
+        for num in [1_u32, 2, 3, 12345, u32::MAX] {
+            let a = OrderScalar::secp256k1(BigUint::from(num));
+
+            assert_eq!(a.clone() * a.inverse().unwrap(), one);
+        }
+
+        let largest = OrderScalar::secp256k1(S256Point::order() - 1_u32);
+        assert_eq!(largest.inverse(), Ok(largest));
+
+        assert_eq!(
+            OrderScalar::secp256k1(S256Point::order()).inverse(),
+            Err(CryptoError::DivByZero)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let n = S256Point::order();
+        let a = OrderScalar::secp256k1(&n - 1_u32);
+        let b = OrderScalar::secp256k1(BigUint::from(2_u32));
+
+        assert_eq!((a.clone() + b.clone()).num, BigUint::from(1_u32));
+        assert_eq!((b.clone() - a.clone()).num, BigUint::from(3_u32));
+        assert_eq!((a.clone() * b.clone()).num, &n - 2_u32);
+        assert_eq!(-a.clone(), OrderScalar::secp256k1(BigUint::from(1_u32)));
+        assert!((a.clone() - a).is_zero());
+
+        // any modulus works, not only n
+        let small = OrderScalar::new(BigUint::from(3_u32), BigUint::from(7_u32));
+        assert_eq!(small.inverse().unwrap().num, BigUint::from(5_u32));
+    }
+
+    #[test]
+    fn test_distinct_from_field() {
+        // n < x < p: a valid coordinate, but not a reduced scalar
+        let x = S256Field::new(S256Point::order() + 5_u32);
+        let r = OrderScalar::from_field(&x);
+
+        assert_eq!(r.num, BigUint::from(5_u32));
+        assert_eq!(x.num, S256Point::order() + 5_u32);
+
+        // the same inverse taken in each type disagrees, as the moduli do
+        let two = BigUint::from(2_u32);
+        assert_ne!(
+            OrderScalar::secp256k1(two.clone()).inverse().unwrap().num,
+            S256Field::new(two).mul_inverse().num
+        );
+    }
+}
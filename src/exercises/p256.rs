@@ -0,0 +1,151 @@
+// NIST P-256 (secp256r1): the curve of TLS, WebAuthn and most non-Bitcoin ECDSA
+//
+// Same shape as secp256k1 (a 256-bit prime, G of prime order n, cofactor 1) but with
+// a = -3 and a "random-looking" b, so all the generic `Point` code runs over it as is
+
+use num_bigint::BigUint;
+
+use crate::exercises::{
+    ec_point::{Point, WeierstrassCurve},
+    field256::prime_field_256,
+};
+
+/*
+ * y² = x³ - 3x + b over 𝔽p, p = 2²⁵⁶ - 2²²⁴ + 2¹⁹² + 2⁹⁶ - 1 (FIPS 186-4, D.1.2.3)
+ */
+const P: &str = "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+const B: &str = "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b";
+const N: &str = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+const GX: &str = "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+const GY: &str = "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+
+fn from_hex(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+}
+
+/*
+ * An element of the P-256 base field
+ */
+prime_field_256!(P256Field, P);
+
+pub type P256Point = Point<P256Field>;
+
+// the curve itself, for code written against `WeierstrassCurve`
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct P256;
+
+impl WeierstrassCurve for P256 {
+    type Field = P256Field;
+
+    // -3, i.e. p - 3
+    fn a() -> P256Field {
+        P256Field::new(P256Field::prime() - BigUint::from(3_u32))
+    }
+
+    fn b() -> P256Field {
+        P256Field::new(from_hex(B))
+    }
+
+    fn field_prime() -> BigUint {
+        P256Field::prime()
+    }
+
+    fn generator() -> P256Point {
+        Self::point(P256Field::new(from_hex(GX)), P256Field::new(from_hex(GY))).unwrap()
+    }
+
+    fn order() -> BigUint {
+        from_hex(N)
+    }
+}
+
+#[cfg(test)]
+mod p256_tests {
+    use super::*;
+    use crate::exercises::{
+        ecdsa::{recover_pubkey, verify, PrivateKey, Signature},
+        s256::Secp256k1,
+    };
+
+    #[test]
+    fn test_generator_times_order_is_infinity() {
+        let g = P256::generator();
+
+        assert!(g.scalar_mul_big(&P256::order()).is_infinity());
+        assert!(!g.scalar_mul_big(&(P256::order() - 1_u32)).is_infinity());
+        assert_eq!(g.scalar_mul_big(&(P256::order() - 1_u32)), -g);
+    }
+
+    #[test]
+    fn test_sec_round_trip() {
+        let point = P256::generator().scalar_mul(0xdead_beef);
+
+        for compressed in [true, false] {
+            assert_eq!(
+                Point::parse_sec(&point.to_sec(compressed), P256::a(), P256::b()),
+                Ok(point.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn test_rfc6979_signature() {
+        // RFC 6979 A.2.5: P-256, SHA-256, message "sample"
+        let secret = from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let private_key = PrivateKey::<P256>::from_secret(secret).unwrap();
+        let public_key = private_key.public_key();
+
+        assert_eq!(
+            public_key,
+            P256::point(
+                P256Field::new(from_hex(
+                    "60fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"
+                )),
+                P256Field::new(from_hex(
+                    "7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299"
+                ))
+            )
+            .unwrap()
+        );
+
+        // sha256("sample")
+        let z = from_hex("af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf");
+        let signature = private_key.sign(&z);
+
+        assert_eq!(
+            signature,
+            Signature::new(
+                from_hex("efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716"),
+                from_hex("f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"),
+            )
+        );
+        assert!(verify::<P256>(&public_key, &z, &signature));
+        assert!(!verify::<P256>(&public_key, &(&z + 1_u32), &signature));
+
+        // the same key and hash mean something else on secp256k1
+        assert!(!verify::<Secp256k1>(
+            &PrivateKey::new(from_hex(
+                "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"
+            ))
+            .unwrap()
+            .public_key(),
+            &z,
+            &signature
+        ));
+    }
+
+    #[test]
+    fn test_recover_pubkey() {
+        let private_key = PrivateKey::<P256>::from_secret(BigUint::from(12345_u32)).unwrap();
+        let z = BigUint::from(6789_u32);
+        let k = BigUint::from(1234567890_u32);
+
+        let signature = private_key.sign_with_k(&z, &k).unwrap();
+        let recovery_id = !P256::generator().scalar_mul_big(&k).y_is_even().unwrap() as u8;
+
+        assert_eq!(
+            recover_pubkey::<P256>(&z, &signature, recovery_id),
+            Ok(private_key.public_key())
+        );
+    }
+}
@@ -0,0 +1,168 @@
+// Polynomials with coefficients in 𝔽p, the building block of interpolation, secret
+// sharing and Reed-Solomon style error correction
+
+use std::ops;
+
+use crate::exercises::finite_field::Fp;
+
+/*
+ * c₀ + c₁x + ... + cₙxⁿ, coefficients lowest degree first. Trailing ZERO coefficients are
+ * dropped on construction so equal polynomials compare equal; the ZERO polynomial has no
+ * coefficients at all
+ */
+#[derive(PartialEq, Debug, Clone)]
+pub struct Polynomial {
+    coefficients: Vec<Fp>,
+}
+
+impl Polynomial {
+    pub fn new(coefficients: Vec<Fp>) -> Self {
+        let mut coefficients = coefficients;
+        while coefficients.last().is_some_and(|c| c.num == 0) {
+            coefficients.pop();
+        }
+
+        Self { coefficients }
+    }
+
+    pub fn coefficients(&self) -> &[Fp] {
+        &self.coefficients
+    }
+
+    // `None` for the ZERO polynomial, whose degree is usually taken to be -∞
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /*
+     * Horner's rule: c₀ + x(c₁ + x(c₂ + ...)), n multiplications and additions for degree
+     * n. Assumes `x` is in the coefficients' field
+     */
+    pub fn eval(&self, x: Fp) -> Fp {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Fp::new_reduced(0, x.modulus), |acc, &c| acc * x + c)
+    }
+}
+
+impl ops::Add for Polynomial {
+    type Output = Self;
+
+    // assumes `rhs` has coefficients in the same field
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut longer, shorter) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self.coefficients, rhs.coefficients)
+        } else {
+            (rhs.coefficients, self.coefficients)
+        };
+
+        for (c, d) in longer.iter_mut().zip(shorter) {
+            *c = *c + d;
+        }
+
+        Self::new(longer)
+    }
+}
+
+impl ops::Mul for Polynomial {
+    type Output = Self;
+
+    /*
+     * The convolution cₖ = Σ_{i+j=k} aᵢ·bⱼ, reduced as it goes by the field arithmetic.
+     * Assumes `rhs` has coefficients in the same field
+     */
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (Some(first), Some(_)) = (self.coefficients.first(), rhs.coefficients.first()) else {
+            return Self::new(vec![]);
+        };
+
+        let zero = Fp::new_reduced(0, first.modulus);
+        let mut product = vec![zero; self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in rhs.coefficients.iter().enumerate() {
+                product[i + j] = product[i + j] + a * b;
+            }
+        }
+
+        Self::new(product)
+    }
+}
+
+#[cfg(test)]
+mod polynomial_tests {
+    use super::*;
+    use crate::exercises::ec_point::Field;
+
+    fn poly(coefficients: &[i64], prime: u32) -> Polynomial {
+        Polynomial::new(
+            coefficients
+                .iter()
+                .map(|&c| Fp::new(c, prime).unwrap())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_square_of_x_plus_one() {
+        let x_plus_one = poly(&[1, 1], 7);
+
+        assert_eq!(x_plus_one.clone() * x_plus_one, poly(&[1, 2, 1], 7));
+        // (x + 1)⁷ = x⁷ + 1 in characteristic 7, the middle binomials vanish
+        let seventh = (0..6).fold(poly(&[1, 1], 7), |p, _| p * poly(&[1, 1], 7));
+        assert_eq!(seventh, poly(&[1, 0, 0, 0, 0, 0, 0, 1], 7));
+    }
+
+    #[test]
+    fn test_add() {
+        assert_eq!(poly(&[1, 2], 7) + poly(&[3, 4, 5], 7), poly(&[4, 6, 5], 7));
+        assert_eq!(poly(&[3, 4, 5], 7) + poly(&[1, 2], 7), poly(&[4, 6, 5], 7));
+        // x² + 3 plus 6x² - 3 cancels down to ZERO
+        let sum = poly(&[3, 0, 1], 7) + poly(&[-3, 0, 6], 7);
+        assert_eq!(sum, poly(&[], 7));
+        assert_eq!(sum.degree(), None);
+    }
+
+    #[test]
+    fn test_degree() {
+        assert_eq!(poly(&[], 7).degree(), None);
+        assert_eq!(poly(&[0, 0], 7).degree(), None);
+        assert_eq!(poly(&[5], 7).degree(), Some(0));
+        assert_eq!(poly(&[1, 2, 1], 7).degree(), Some(2));
+        assert_eq!(poly(&[1, 2, 7], 7).degree(), Some(1));
+        assert_eq!((poly(&[1, 2, 1], 7) * poly(&[], 7)).degree(), None);
+    }
+
+    #[test]
+    fn test_eval_matches_sum_of_powers() {
+        let prime = 97;
+        let p = poly(&[11, -5, 0, 3, 42], prime);
+
+        for x in 0..prime {
+            let x = Fp::new_reduced(x, prime);
+            let expected = p
+                .coefficients()
+                .iter()
+                .enumerate()
+                .fold(x.zero(), |sum, (i, &c)| sum + c * x.pow(i as u32));
+
+            assert_eq!(p.eval(x), expected);
+        }
+
+        let five = Fp::new_reduced(5, prime);
+        assert_eq!(poly(&[], prime).eval(five), five.zero());
+    }
+
+    #[test]
+    fn test_mul_evaluates_to_product() {
+        let prime = 101;
+        let (p, q) = (poly(&[4, 0, 9], prime), poly(&[-1, 7, 2, 3], prime));
+        let product = p.clone() * q.clone();
+
+        assert_eq!(product.degree(), Some(5));
+        for x in [0, 1, 2, 50, 100] {
+            let x = Fp::new_reduced(x, prime);
+            assert_eq!(product.eval(x), p.eval(x) * q.eval(x));
+        }
+    }
+}
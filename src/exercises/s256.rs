@@ -0,0 +1,365 @@
+// Book: Programming Bitcoin: Learn How to Program Bitcoin from Scratch by Jimmy song
+// Chapter 3: Elliptic Curve Cryptography — secp256k1
+
+use num_bigint::BigUint;
+
+use crate::exercises::{
+    base58, bech32,
+    ec_point::{Point, WeierstrassCurve},
+    error::CryptoError,
+    field256::prime_field_256,
+    hashes::hash160,
+};
+
+/*
+ * secp256k1: y² = x³ + 7 over 𝔽p, p = 2²⁵⁶ - 2³² - 977
+ * G generates the whole group, which has prime order n (the cofactor is 1)
+ */
+const P: &str = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+const N: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+const GX: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+const GY: &str = "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+fn from_hex(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+}
+
+/*
+ * An element of the secp256k1 base field
+ */
+prime_field_256!(S256Field, P);
+
+pub type S256Point = Point<S256Field>;
+
+// the curve itself, for code written against `WeierstrassCurve`
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Secp256k1;
+
+impl Point<S256Field> {
+    pub fn from_coordinates(x: BigUint, y: BigUint) -> Result<Self, CryptoError> {
+        Secp256k1::point(S256Field::new(x), S256Field::new(y))
+    }
+
+    pub fn generator() -> Self {
+        Secp256k1::generator()
+    }
+
+    // the order n of the group generated by G, n·G = P(∞)
+    pub fn order() -> BigUint {
+        Secp256k1::order()
+    }
+
+    /*
+     * P2PKH address: Base58Check of 0x00 (mainnet) / 0x6f (testnet) || hash160(SEC)
+     *
+     * The compressed and uncompressed SEC encodings hash differently, so the same key
+     * has two addresses per network
+     */
+    pub fn address(&self, compressed: bool, testnet: bool) -> String {
+        let mut payload = vec![if testnet { 0x6f } else { 0x00 }];
+        payload.extend_from_slice(&hash160(&self.to_sec(compressed)));
+
+        base58::encode_check(&payload)
+    }
+
+    /*
+     * P2WPKH (SegWit v0) address: Bech32 with the hrp "bc" (mainnet) / "tb" (testnet) of
+     * witness version 0 and the program hash160(compressed SEC)
+     *
+     * Only compressed keys are standard in SegWit, so unlike `address` there is one
+     * address per network
+     */
+    pub fn segwit_address(&self, testnet: bool) -> Result<String, String> {
+        if self.is_infinity() {
+            return Err("the point at infinity has no address".to_string());
+        }
+
+        let hrp = if testnet { "tb" } else { "bc" };
+
+        bech32::encode_segwit(hrp, 0, &hash160(&self.to_sec(true)))
+    }
+}
+
+impl WeierstrassCurve for Secp256k1 {
+    type Field = S256Field;
+
+    fn a() -> S256Field {
+        S256Field::new(BigUint::from(0_u32))
+    }
+
+    fn b() -> S256Field {
+        S256Field::new(BigUint::from(7_u32))
+    }
+
+    fn field_prime() -> BigUint {
+        S256Field::prime()
+    }
+
+    fn generator() -> S256Point {
+        Self::point(S256Field::new(from_hex(GX)), S256Field::new(from_hex(GY))).unwrap()
+    }
+
+    fn order() -> BigUint {
+        from_hex(N)
+    }
+}
+
+/*
+ * secp256k1 is fixed, so unlike a `Point` over some `Fp` its points have a curve to
+ * default to: P(∞), the identity
+ */
+impl Default for Point<S256Field> {
+    fn default() -> Self {
+        Secp256k1::infinity()
+    }
+}
+
+// SEC bytes (either form) of a secp256k1 point, so `bytes.try_into()` works on keys
+impl TryFrom<&[u8]> for Point<S256Field> {
+    type Error = CryptoError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::parse_sec(bytes, Secp256k1::a(), Secp256k1::b())
+    }
+}
+
+// 64 hex digits, parsed back with `with_be_bytes` so values ≥ p are rejected
+#[cfg(feature = "serde")]
+impl serde::Serialize for S256Field {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for S256Field {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use crate::exercises::ec_point::PrimeField;
+
+        let hex_digits = <String as serde::Deserialize>::deserialize(deserializer)?;
+        let bytes = hex::decode(hex_digits).map_err(serde::de::Error::custom)?;
+
+        S256Field::new(BigUint::from(0_u32))
+            .with_be_bytes(&bytes)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod s256_tests {
+    use super::*;
+
+    #[test]
+    fn test_generator_is_on_curve() {
+        let g = S256Point::generator();
+        let (a, b) = (Secp256k1::a(), Secp256k1::b());
+
+        assert!(Point::is_on_curve(
+            g.x.as_ref().unwrap(),
+            g.y.as_ref().unwrap(),
+            &a,
+            &b
+        ));
+        assert!(crate::exercises::ec_point::is_non_singular(a, b));
+    }
+
+    #[test]
+    fn test_generator_times_order_is_infinity() {
+        let g = S256Point::generator();
+
+        assert!(g.scalar_mul_big(&S256Point::order()).is_infinity());
+        assert_eq!(
+            g.scalar_mul_big(&(S256Point::order() + BigUint::from(1_u32))),
+            g
+        );
+    }
+
+    #[test]
+    fn test_sec_round_trip() {
+        let point = S256Point::generator().scalar_mul(5001);
+        let (a, b) = (Secp256k1::a(), Secp256k1::b());
+
+        for compressed in [false, true] {
+            let sec = point.to_sec(compressed);
+
+            assert_eq!(sec.len(), if compressed { 33 } else { 65 });
+            assert_eq!(
+                Point::parse_sec(&sec, a.clone(), b.clone()),
+                Ok(point.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn test_generator_is_in_subgroup() {
+        let order = S256Point::order();
+
+        assert!(S256Point::generator().is_in_subgroup(&order));
+        assert!(S256Point::generator()
+            .scalar_mul(12345)
+            .is_in_subgroup(&order));
+    }
+
+    #[test]
+    fn test_default_is_infinity() {
+        let default = S256Point::default();
+
+        assert!(default.is_infinity());
+        assert!(Secp256k1::contains(&default));
+        assert_eq!(
+            (S256Point::generator() + default).unwrap(),
+            S256Point::generator()
+        );
+
+        let points: Vec<S256Point> = vec![Default::default(); 3];
+        assert!(points.iter().all(S256Point::is_infinity));
+    }
+
+    #[test]
+    fn test_reference_addition() {
+        // S256Point is not `Copy`, both operands stay usable
+        let g = S256Point::generator();
+        let h = g.scalar_mul(7);
+
+        assert_eq!(&g + &h, Ok(g.scalar_mul(8)));
+        assert_eq!(&h - &g, Ok(g.scalar_mul(6)));
+        assert_eq!(&g + &g, g.double());
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_scalar_mul_blinded() {
+        let mut rng = rand::thread_rng();
+        let generator = S256Point::generator();
+        let secret = from_hex("8b387de39861728c92ec9f589c303b1038ff60eb3963b12cd212263a1d1e0f00");
+        let expected = generator.scalar_mul_big(&secret);
+
+        for _ in 0..4 {
+            assert_eq!(
+                generator.scalar_mul_blinded(&secret, &S256Point::order(), &mut rng),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_from_sec_bytes() {
+        let point = S256Point::generator().scalar_mul(5001);
+
+        for compressed in [true, false] {
+            let sec = point.to_sec(compressed);
+            let parsed: S256Point = sec.as_slice().try_into().unwrap();
+
+            assert_eq!(parsed, point);
+            assert_eq!(S256Point::try_from(&sec[..]), Ok(point.clone()));
+        }
+
+        assert_eq!(
+            S256Point::try_from(&point.to_sec(true)[..20]),
+            Err(CryptoError::InvalidSecLength {
+                length: 20,
+                prefix: 0x03,
+                expected: 33
+            })
+        );
+        assert_eq!(S256Point::try_from(&[][..]), Err(CryptoError::EmptySec));
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        use crate::exercises::ec_point::hash_to_curve;
+
+        let point = hash_to_curve(b"H", Secp256k1::a(), Secp256k1::b());
+
+        assert_eq!(hash_to_curve(b"H", Secp256k1::a(), Secp256k1::b()), point);
+        assert!(Secp256k1::point(point.x.clone().unwrap(), point.y.clone().unwrap()).is_ok());
+        assert!(point.is_in_subgroup(&S256Point::order()));
+
+        let points: Vec<_> = ["", "a", "b", "ab", "ba"]
+            .iter()
+            .map(|msg| hash_to_curve(msg.as_bytes(), Secp256k1::a(), Secp256k1::b()))
+            .collect();
+        for (i, p) in points.iter().enumerate() {
+            assert!(points[i + 1..].iter().all(|q| q != p));
+        }
+    }
+
+    #[test]
+    fn test_address() {
+        let public_key = S256Point::generator().scalar_mul(5002);
+
+        assert_eq!(
+            public_key.address(true, false),
+            "1BMERxWc9yF8gYAob2FkngUJgnHuB8AUaN"
+        );
+        assert_eq!(
+            public_key.address(true, true),
+            "mqsBj1baxzgPTeeRJbE8cbgdYmtc3yess3"
+        );
+        assert_eq!(
+            public_key.address(false, false),
+            "16wSJUKH9aMz7Fx9E6iiV9oR4eHaMFaWCB"
+        );
+        // Programming Bitcoin, chapter 4 exercise 5
+        assert_eq!(
+            public_key.address(false, true),
+            "mmTPbXQFxboEtNRkwfh6K51jvdtHLxGeMA"
+        );
+    }
+
+    #[test]
+    fn test_segwit_address() {
+        // BIP-173: the P2WPKH addresses of the compressed generator
+        let public_key = S256Point::generator();
+
+        assert_eq!(
+            public_key.segwit_address(false),
+            Ok("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4".to_string())
+        );
+        assert_eq!(
+            public_key.segwit_address(true),
+            Ok("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".to_string())
+        );
+
+        assert!(S256Point::generator()
+            .scalar_mul_big(&S256Point::order())
+            .segwit_address(false)
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let point = S256Point::generator().scalar_mul(5001);
+        let json = serde_json::to_string(&point).unwrap();
+
+        assert_eq!(serde_json::from_str::<S256Point>(&json).unwrap(), point);
+
+        // flip the lowest bit of y
+        let mut sec = point.to_sec(false);
+        sec[64] ^= 1;
+        let tampered = json.replace(&hex::encode(point.to_sec(false)), &hex::encode(sec));
+        assert!(serde_json::from_str::<S256Point>(&tampered)
+            .unwrap_err()
+            .to_string()
+            .contains("is not on the curve"));
+    }
+
+    #[test]
+    fn test_sec_hex() {
+        // Programming Bitcoin, chapter 4 exercises 1 and 2
+        let uncompressed = S256Point::generator().scalar_mul(5000);
+        let compressed = S256Point::generator().scalar_mul(5001);
+
+        assert_eq!(
+            uncompressed.to_hex(false),
+            "04ffe558e388852f0120e46af2d1b370f85854a8eb0841811ece0e3e03d282d57c\
+             315dc72890a4f10a1481c031b03b351b0dc79901ca18a00cf009dbdb157a1d10"
+        );
+        assert_eq!(
+            format!("{:x}", compressed),
+            "0357a4f368868a8a6d572991e484e664810ff14c05c0fa023275251151fe0e53d1"
+        );
+        assert_eq!(compressed.to_hex(true), format!("{:x}", compressed));
+    }
+}
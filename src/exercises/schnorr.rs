@@ -0,0 +1,312 @@
+// BIP-340: Schnorr signatures for secp256k1
+//
+// Public keys are x-only (32 bytes, the point with that x and an even y) and signatures
+// are R.x || s (64 bytes)
+
+use num_bigint::BigUint;
+
+use crate::exercises::{
+    ec_point::{Point, PrimeField},
+    ecdsa::to_32_bytes,
+    hashes::tagged_hash,
+    s256::{S256Field, S256Point},
+};
+
+/*
+ * d' = secret, P = d'·G, d = d' if P.y is even else n - d'
+ * k' = tagged_hash("BIP0340/nonce", (d ⊕ tagged_hash("BIP0340/aux", aux_rand)) || P.x || m)
+ * R = k'·G, k = k' if R.y is even else n - k'
+ * e = tagged_hash("BIP0340/challenge", R.x || P.x || m)
+ *
+ * signature = R.x || (k + e·d) mod n
+ */
+pub fn sign(secret: &BigUint, message: &[u8; 32], aux_rand: &[u8; 32]) -> Result<[u8; 64], String> {
+    let n = S256Point::order();
+
+    if *secret == BigUint::from(0_u32) || *secret >= n {
+        return Err("private key must be between 1 and n - 1".to_string());
+    }
+
+    let public_key = S256Point::generator().scalar_mul_big(secret);
+    let d = if has_even_y(&public_key) {
+        secret.clone()
+    } else {
+        &n - secret
+    };
+    let public_key_x = x_bytes(&public_key);
+
+    let masked: Vec<u8> = to_32_bytes(&d)
+        .iter()
+        .zip(tagged_hash("BIP0340/aux", aux_rand))
+        .map(|(byte, mask)| byte ^ mask)
+        .collect();
+    let nonce = tagged_hash(
+        "BIP0340/nonce",
+        &[&masked[..], &public_key_x, message].concat(),
+    );
+
+    let k_prime = BigUint::from_bytes_be(&nonce) % &n;
+    if k_prime == BigUint::from(0_u32) {
+        return Err("nonce is zero, use a different aux_rand".to_string());
+    }
+
+    let nonce_point = S256Point::generator().scalar_mul_big(&k_prime);
+    let k = if has_even_y(&nonce_point) {
+        k_prime
+    } else {
+        &n - k_prime
+    };
+    let nonce_point_x = x_bytes(&nonce_point);
+
+    let e = challenge(&nonce_point_x, &public_key_x, message);
+    let s = (k + e * d) % &n;
+
+    let mut signature = [0_u8; 64];
+    signature[..32].copy_from_slice(&nonce_point_x);
+    signature[32..].copy_from_slice(&to_32_bytes(&s));
+
+    Ok(signature)
+}
+
+/*
+ * valid iff R = s·G - e·P is not P(∞), has an even y and R.x = r
+ *
+ * with P the even-y point of `pubkey_x`, r = sig[0..32] < p and s = sig[32..64] < n
+ */
+pub fn verify(pubkey_x: &[u8; 32], message: &[u8; 32], sig: &[u8; 64]) -> bool {
+    let n = S256Point::order();
+
+    let public_key = match lift_x(pubkey_x) {
+        Some(point) => point,
+        None => return false,
+    };
+
+    let r = BigUint::from_bytes_be(&sig[..32]);
+    let s = BigUint::from_bytes_be(&sig[32..]);
+    if r >= S256Field::prime() || s >= n {
+        return false;
+    }
+
+    let e = challenge(&sig[..32], pubkey_x, message);
+    let minus_e = (&n - e) % &n;
+
+    match S256Point::generator().scalar_mul_big(&s) + public_key.scalar_mul_big(&minus_e) {
+        Ok(nonce_point) => match &nonce_point.x {
+            Some(x) => has_even_y(&nonce_point) && x.num == r,
+            None => false,
+        },
+        Err(_) => false,
+    }
+}
+
+// the point with x-coordinate `x` and an even y, if there is one
+fn lift_x(x: &[u8; 32]) -> Option<S256Point> {
+    let g = S256Point::generator();
+    let x = g.x.as_ref()?.with_be_bytes(x).ok()?;
+    let (point, negated) = Point::from_x(x, g.a.clone(), g.b.clone()).ok()?;
+
+    Some(if has_even_y(&point) { point } else { negated })
+}
+
+fn has_even_y(point: &S256Point) -> bool {
+    point.y_is_even() == Some(true)
+}
+
+fn x_bytes(point: &S256Point) -> Vec<u8> {
+    point
+        .x
+        .as_ref()
+        .map(|x| x.to_be_bytes())
+        .unwrap_or_default()
+}
+
+fn challenge(nonce_point_x: &[u8], public_key_x: &[u8], message: &[u8]) -> BigUint {
+    let hash = tagged_hash(
+        "BIP0340/challenge",
+        &[nonce_point_x, public_key_x, message].concat(),
+    );
+
+    BigUint::from_bytes_be(&hash) % S256Point::order()
+}
+
+#[cfg(test)]
+mod schnorr_tests {
+    use super::*;
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0_u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+
+        bytes
+    }
+
+    // BIP-340 test vectors 0-3: (secret key, public key, aux_rand, message, signature)
+    const SIGN_VECTORS: [(&str, &str, &str, &str, &str); 4] = [
+        (
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA8215\
+             25F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+        ),
+        (
+            "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE3341\
+             8906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+        ),
+        (
+            "C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9",
+            "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+            "C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906",
+            "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+            "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1B\
+             AB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+        ),
+        (
+            "0B432B2677937381AEF05BB02A66ECD012773062CF3FA2549E44F58ED2401710",
+            "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+            "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC\
+             97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3",
+        ),
+    ];
+
+    // BIP-340 test vectors 4-14: (public key, message, signature, valid)
+    const VERIFY_VECTORS: [(&str, &str, &str, bool); 11] = [
+        (
+            "D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9",
+            "4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703",
+            "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C63\
+             76AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4",
+            true,
+        ),
+        // public key not on the curve
+        (
+            "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+             69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+        // R has an odd y
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "FFF97BD5755EEEA420453A14355235D382F6472F8568A18B2F057A1460297556\
+             3CC27944640AC607CD107AE10923D9EF7A73C643E166BE5EBEAFA34B1AC553E2",
+            false,
+        ),
+        // negated message
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "1FA62E331EDBC21C394792D2AB1100A7B432B013DF3F6FF4F99FCB33E0E1515F\
+             28890B3EDB6E7189B630448B515CE4F8622A954CFE545735AAEA5134FCCDB2BD",
+            false,
+        ),
+        // negated s
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+             961764B3AA9B2FFCB6EF947B6887A226E8D7C93E00C5ED0C1834FF0D0C2E6DA6",
+            false,
+        ),
+        // s·G - e·P is P(∞), with r = 0
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             123DDA8328AF9C23A94C1FEECFD123BA4FB73476F0D594DCB65C6425BD186051",
+            false,
+        ),
+        // s·G - e·P is P(∞), with r = 1
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             7615FBAF5AE28864013C099742DEADB4DBA87F11AC6754F93780D5A1837CF197",
+            false,
+        ),
+        // r is not the x-coordinate of a point on the curve
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D\
+             69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+        // r = p
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F\
+             69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+        // s = n
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+             FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            false,
+        ),
+        // public key x exceeds the field size
+        (
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+             69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+    ];
+
+    #[test]
+    fn test_sign_vectors() {
+        for (secret, public_key, aux_rand, message, signature) in SIGN_VECTORS {
+            let secret = BigUint::from_bytes_be(&from_hex::<32>(secret));
+            let public_key = from_hex::<32>(public_key);
+            let message = from_hex::<32>(message);
+            let expected = from_hex::<64>(signature);
+
+            assert_eq!(
+                x_bytes(&S256Point::generator().scalar_mul_big(&secret)),
+                public_key.to_vec()
+            );
+            assert_eq!(sign(&secret, &message, &from_hex(aux_rand)), Ok(expected));
+            assert!(verify(&public_key, &message, &expected));
+        }
+    }
+
+    #[test]
+    fn test_verify_vectors() {
+        for (i, (public_key, message, signature, valid)) in VERIFY_VECTORS.into_iter().enumerate() {
+            assert_eq!(
+                verify(
+                    &from_hex(public_key),
+                    &from_hex(message),
+                    &from_hex(signature)
+                ),
+                valid,
+                "vector {}",
+                i + 4
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_invalid_secret() {
+        assert_eq!(
+            sign(&BigUint::from(0_u32), &[0; 32], &[0; 32]),
+            Err("private key must be between 1 and n - 1".to_string())
+        );
+    }
+}
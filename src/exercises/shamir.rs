@@ -0,0 +1,165 @@
+// Shamir secret sharing: a secret split into n shares, any t of which recover it while
+// t - 1 of them say nothing about it
+//
+// The secret is the constant term of a random polynomial f of degree t - 1 over 𝔽p and
+// the shares are the points (i, f(i)), i = 1..n. t points pin down f, fewer fit every
+// possible constant term equally well
+
+use crate::exercises::{finite_field::Fp, lagrange::lagrange_interpolate};
+
+/*
+ * `n` shares of `secret`, any `t` of which recover it. The share indices are 1..=n,
+ * which have to be distinct non-zero elements of the field, so n < p. Panics when
+ * t = 0, t > n or n ≥ p
+ */
+#[cfg(feature = "rand")]
+pub fn split<R: rand::Rng>(secret: Fp, t: usize, n: usize, rng: &mut R) -> Vec<(u32, Fp)> {
+    let modulus = secret.modulus;
+    assert!(t >= 1 && t <= n, "need 1 ≤ t ≤ n, got t = {}, n = {}", t, n);
+    assert!(
+        (n as u64) < modulus as u64,
+        "{} shares do not fit in 𝔽{}",
+        n,
+        modulus
+    );
+
+    // f(x) = secret + c₁x + ... + c_{t-1}x^(t-1)
+    let mut coefficients = vec![secret];
+    coefficients.extend((1..t).map(|_| Fp::new_reduced(rng.gen_range(0..modulus), modulus)));
+
+    (1..=n as u32)
+        .map(|index| {
+            let x = Fp::new_reduced(index, modulus);
+            // Horner: f(x) = (...(c_{t-1}·x + c_{t-2})·x + ...)·x + secret
+            let y = coefficients
+                .iter()
+                .rev()
+                .fold(Fp::new_reduced(0, modulus), |acc, &c| acc * x + c);
+
+            (index, y)
+        })
+        .collect()
+}
+
+/*
+ * The secret f(0) from shares (i, f(i)), by `lagrange_interpolate` at zero:
+ *
+ * f(0) = Σ yᵢ · Π_{j≠i} xⱼ / (xⱼ - xᵢ)
+ *
+ * Given fewer than t shares this still returns an element, just not the secret
+ */
+pub fn recover(shares: &[(u32, Fp)]) -> Result<Fp, String> {
+    let modulus = match shares.first() {
+        Some((_, y)) => y.modulus,
+        None => return Err("cannot recover a secret from no shares".to_string()),
+    };
+    if shares.iter().any(|(_, y)| y.modulus != modulus) {
+        return Err("shares are in different fields".to_string());
+    }
+
+    let xs: Vec<Fp> = shares
+        .iter()
+        .map(|&(index, _)| Fp::new_reduced(index, modulus))
+        .collect();
+    for (i, x) in xs.iter().enumerate() {
+        if x.num == 0 || xs[..i].contains(x) {
+            return Err(format!("share index {} is zero or repeated", shares[i].0));
+        }
+    }
+
+    let points: Vec<(Fp, Fp)> = xs.into_iter().zip(shares.iter().map(|&(_, y)| y)).collect();
+
+    lagrange_interpolate(&points, Fp::new_reduced(0, modulus))
+}
+
+#[cfg(test)]
+mod shamir_tests {
+    use super::*;
+
+    const PRIME: u32 = 7919;
+
+    // f(x) = 1234 + 166x + 94x², so t = 3
+    fn shares() -> Vec<(u32, Fp)> {
+        [
+            (1, 1494),
+            (2, 1942),
+            (3, 2578),
+            (4, 3402),
+            (5, 4414),
+            (6, 5614),
+        ]
+        .into_iter()
+        .map(|(x, y)| (x, Fp::new(y, PRIME).unwrap()))
+        .collect()
+    }
+
+    #[test]
+    fn test_recover_any_three() {
+        let shares = shares();
+        let secret = Fp::new(1234, PRIME).unwrap();
+
+        for i in 0..shares.len() {
+            for j in i + 1..shares.len() {
+                for k in j + 1..shares.len() {
+                    let subset = [shares[i], shares[j], shares[k]];
+                    assert_eq!(recover(&subset), Ok(secret));
+                }
+
+                assert_ne!(recover(&[shares[i], shares[j]]), Ok(secret));
+            }
+        }
+
+        // extra shares lie on the same polynomial and change nothing
+        assert_eq!(recover(&shares), Ok(secret));
+    }
+
+    #[test]
+    fn test_recover_errors() {
+        let shares = shares();
+
+        assert_eq!(
+            recover(&[]),
+            Err("cannot recover a secret from no shares".to_string())
+        );
+        assert_eq!(
+            recover(&[shares[0], (2, Fp::new(1942, 7907).unwrap())]),
+            Err("shares are in different fields".to_string())
+        );
+        assert_eq!(
+            recover(&[shares[0], shares[1], shares[0]]),
+            Err("share index 1 is zero or repeated".to_string())
+        );
+        assert_eq!(
+            recover(&[shares[0], (PRIME, Fp::new(1234, PRIME).unwrap())]),
+            Err(format!("share index {} is zero or repeated", PRIME))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_split_and_recover() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(357);
+        let modulus = 2_147_483_647;
+
+        for (secret, t, n) in [(0, 1, 1), (42, 2, 3), (123_456_789, 3, 5), (7, 5, 5)] {
+            let secret = Fp::new(secret, modulus).unwrap();
+            let shares = split(secret, t, n, &mut rng);
+
+            assert_eq!(shares.len(), n);
+            assert_eq!(recover(&shares), Ok(secret));
+            assert_eq!(recover(&shares[n - t..]), Ok(secret));
+            assert_eq!(recover(&shares[..t]), Ok(secret));
+            if t > 1 {
+                assert_ne!(recover(&shares[..t - 1]), Ok(secret));
+            }
+        }
+
+        // with t = 1 every share is the secret itself
+        let secret = Fp::new(99, modulus).unwrap();
+        for (_, share) in split(secret, 1, 4, &mut rng) {
+            assert_eq!(share, secret);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// Book: Programming Bitcoin: Learn How to Program Bitcoin from Scratch by Jimmy song
+// Chapter 5: Transactions — varints (CompactSize)
+
+/*
+ * n < 0xfd          n as a single byte
+ * n ≤ 0xffff        0xfd || n as 2 little-endian bytes
+ * n ≤ 0xffffffff    0xfe || n as 4 little-endian bytes
+ * otherwise         0xff || n as 8 little-endian bytes
+ */
+pub fn encode(n: u64) -> Vec<u8> {
+    let (prefix, width) = match n {
+        0..=0xfc => return vec![n as u8],
+        0xfd..=0xffff => (0xfd, 2),
+        0x1_0000..=0xffff_ffff => (0xfe, 4),
+        _ => (0xff, 8),
+    };
+
+    let mut bytes = vec![prefix];
+    bytes.extend_from_slice(&n.to_le_bytes()[..width]);
+
+    bytes
+}
+
+/*
+ * The varint at the start of `bytes` and how many bytes it took up, the rest (if any) is
+ * left for the caller
+ *
+ * Like Bitcoin Core, a value written with a longer prefix than it needs is rejected, so
+ * every number has exactly one encoding
+ */
+pub fn read(bytes: &[u8]) -> Result<(u64, usize), String> {
+    let (width, minimum) = match bytes.first() {
+        None => return Err("varint is empty".to_string()),
+        Some(&byte) if byte < 0xfd => return Ok((byte as u64, 1)),
+        Some(0xfd) => (2, 0xfd),
+        Some(0xfe) => (4, 0x1_0000),
+        Some(_) => (8, 0x1_0000_0000),
+    };
+
+    if bytes.len() < 1 + width {
+        return Err(format!(
+            "varint prefix 0x{:02x} needs {} more bytes, got {}",
+            bytes[0],
+            width,
+            bytes.len() - 1
+        ));
+    }
+
+    let mut le_bytes = [0_u8; 8];
+    le_bytes[..width].copy_from_slice(&bytes[1..1 + width]);
+    let n = u64::from_le_bytes(le_bytes);
+
+    if n < minimum {
+        return Err(format!("non-canonical varint for {}", n));
+    }
+
+    Ok((n, 1 + width))
+}
+
+#[cfg(test)]
+mod varint_tests {
+    use super::*;
+
+    #[test]
+    fn test_size_boundaries() {
+        let vectors: [(u64, &[u8]); 8] = [
+            (0, &[0x00]),
+            (0xfc, &[0xfc]),
+            (0xfd, &[0xfd, 0xfd, 0x00]),
+            (0xffff, &[0xfd, 0xff, 0xff]),
+            (0x1_0000, &[0xfe, 0x00, 0x00, 0x01, 0x00]),
+            (0xffff_ffff, &[0xfe, 0xff, 0xff, 0xff, 0xff]),
+            (
+                0x1_0000_0000,
+                &[0xff, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00],
+            ),
+            (u64::MAX, &[0xff; 9]),
+        ];
+
+        for (n, expected) in vectors {
+            assert_eq!(encode(n), expected);
+            assert_eq!(read(expected), Ok((n, expected.len())));
+        }
+    }
+
+    #[test]
+    fn test_read_leaves_the_rest() {
+        // a 100-byte script length followed by the script
+        let bytes = [0x64, 0x76, 0xa9];
+
+        assert_eq!(read(&bytes), Ok((100, 1)));
+    }
+
+    #[test]
+    fn test_read_errors() {
+        assert_eq!(read(&[]), Err("varint is empty".to_string()));
+        assert_eq!(
+            read(&[0xfe, 0x01, 0x02]),
+            Err("varint prefix 0xfe needs 4 more bytes, got 2".to_string())
+        );
+        assert_eq!(
+            read(&[0xfd, 0xfc, 0x00]),
+            Err("non-canonical varint for 252".to_string())
+        );
+        assert_eq!(
+            read(&[0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00]),
+            Err("non-canonical varint for 4294967295".to_string())
+        );
+    }
+}
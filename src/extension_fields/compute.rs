@@ -51,9 +51,9 @@ pub fn main() {
             let derived_point = Point::new(gf(a), gf(b), gf(x).into(), gf(root as i64).into());
 
             // 1b. test the point
-            if derived_point.is_ok() {
+            if let Ok(derived_point) = derived_point {
                 // println!("• ({}, {})", x, root);
-                points.push(derived_point.unwrap());
+                points.push(derived_point);
 
                 if root != 0 {
                     let root_additive_inverse = MODULUS - root as u32;
@@ -108,16 +108,11 @@ pub fn prototype() {
     for x in field_elements[5000..10000].iter() {
         for y in field_elements.iter() {
             let point = Point::new(Fp2::new(-1, 0), Fp2::new(-1, 0), Some(*x), Some(*y));
-            if point.is_ok()
-                && !point.clone().unwrap().is_infinity()
-                && point
-                    .clone()
-                    .unwrap()
-                    .scalar_mul(SUBGROUP_ORDER_R)
-                    .is_infinity()
-            {
-                println!("G2 {}", point.unwrap());
-                return;
+            if let Ok(point) = point {
+                if !point.is_infinity() && point.scalar_mul(SUBGROUP_ORDER_R).is_infinity() {
+                    println!("G2 {}", point);
+                    return;
+                }
             }
         }
     }
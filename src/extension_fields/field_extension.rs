@@ -41,33 +41,29 @@ impl Fp2 {
 }
 
 impl Field for Fp2 {
-    fn zero(modulus: u32) -> Self {
+    fn zero(&self) -> Self {
         Self::new(0, 0)
     }
 
-    fn one(modulus: u32) -> Self {
+    fn one(&self) -> Self {
         Self::new(1, 0)
     }
 
-    fn modulus(&self) -> u32 {
-        self.a.modulus
-    }
-
-    fn is_zero(self) -> bool {
+    fn is_zero(&self) -> bool {
         self.a.is_zero() && self.b.is_zero()
     }
 
-    fn pow(self, exponent: u32) -> Self {
-        let mut res: Fp2 = self.clone();
+    fn pow(&self, exponent: u32) -> Self {
+        let mut res: Fp2 = *self;
 
         for _ in 0..(exponent - 1) {
-            res = res * self;
+            res = res * *self;
         }
 
         res
     }
 
-    fn mul_inverse(self) -> Self {
+    fn mul_inverse(&self) -> Self {
         let beta = Self::select_quadratic_non_residue_beta();
 
         let norm = (self.a * self.a) - (self.b * self.b * beta);
@@ -78,14 +74,14 @@ impl Field for Fp2 {
         }
     }
 
-    fn add_inverse(self) -> Self {
+    fn add_inverse(&self) -> Self {
         Self {
             a: -self.a,
             b: -self.b,
         }
     }
 
-    fn scalar_mul(self, by: u32) -> Self {
+    fn scalar_mul(&self, by: u32) -> Self {
         Self {
             a: self.a.scalar_mul(by),
             b: self.b.scalar_mul(by),
@@ -142,6 +138,7 @@ impl ops::Mul for Fp2 {
 impl ops::Div for Fp2 {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, rhs: Self) -> Self::Output {
         self * rhs.mul_inverse()
     }
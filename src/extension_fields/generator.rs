@@ -3,7 +3,7 @@ use crate::{
     extension_fields::utils::SUBGROUP_ORDER_R,
 };
 
-pub fn find_g1s(points: &Vec<Point<Fp>>) -> Vec<&Point<Fp>> {
+pub fn find_g1s(points: &[Point<Fp>]) -> Vec<&Point<Fp>> {
     let mut g1s: Vec<&Point<Fp>> = vec![];
 
     for point in points.iter() {
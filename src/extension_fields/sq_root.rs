@@ -25,7 +25,7 @@ pub fn tonelli_shanks(specimen: &Fp) -> Option<u64> {
     let mut q = p - 1;
     let mut s = 0;
 
-    while q % 2 == 0 {
+    while q.is_multiple_of(2) {
         q /= 2;
         s += 1;
     }
@@ -38,7 +38,7 @@ pub fn tonelli_shanks(specimen: &Fp) -> Option<u64> {
 
     let mut c = mod_pow(z, q, p);
     let mut t = mod_pow(n, q, p);
-    let mut r = mod_pow(n, (q + 1) / 2, p);
+    let mut r = mod_pow(n, q.div_ceil(2), p);
 
     let mut m = s;
 
@@ -94,7 +94,5 @@ fn mod_pow(base: u64, mut exp: u64, p: u64) -> u64 {
 }
 
 fn legendre_symbol(a: u64, p: u64) -> u64 {
-    let ls = mod_pow(a % p, (p - 1) / 2, p);
-
-    ls
+    mod_pow(a % p, (p - 1) / 2, p)
 }
@@ -21,7 +21,7 @@ fn divisors(n: u64) -> Vec<u64> {
 
     let mut i = 1;
     while i * i <= n {
-        if n % i == 0 {
+        if n.is_multiple_of(i) {
             small.push(i);
             if i != n / i {
                 large.push(n / i);
@@ -38,7 +38,7 @@ pub fn embedding_degree(subgroup_order: u32) -> Option<u32> {
     let p = MODULUS as u64;
     let r = subgroup_order as u64;
 
-    if p % r == 0 {
+    if p.is_multiple_of(r) {
         return None;
     }
 
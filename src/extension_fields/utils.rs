@@ -1,5 +1,3 @@
-use std::u32;
-
 use crate::exercises::{ec_point::Field, finite_field::Fp};
 
 pub const MODULUS: u32 = 157;
@@ -55,10 +53,10 @@ pub fn prime_factors(n: u32) -> Vec<u32> {
             break;
         }
 
-        if n_mut % prime == 0 {
+        if n_mut.is_multiple_of(prime) {
             factors.push(prime);
 
-            while n_mut % prime == 0 {
+            while n_mut.is_multiple_of(prime) {
                 n_mut /= prime;
             }
         }
@@ -1,6 +1,44 @@
-mod exercises;
-mod extension_fields;
+use std::{env, process};
 
+use cryptography::{exercises::addition_table::addition_table, extension_fields};
+
+/*
+ * cargo run                                   => extension field exploration
+ * cargo run -- table --prime 5 --a 1 --b 1    => point addition table of y² = x³ + x + 1 over 𝔽5
+ */
 fn main() {
-    extension_fields::compute::main();
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("table") => match table(&args[1..]) {
+            Ok(table) => println!("{}", table),
+            Err(error) => {
+                eprintln!("error: {}", error);
+                process::exit(1);
+            }
+        },
+        _ => extension_fields::compute::main(),
+    }
+}
+
+fn table(args: &[String]) -> Result<String, String> {
+    let mut prime = None;
+    let mut a = None;
+    let mut b = None;
+
+    for flag in args.chunks(2) {
+        let value = flag
+            .get(1)
+            .ok_or(format!("missing value for `{}`", flag[0]))?;
+
+        match flag[0].as_str() {
+            "--prime" => prime = Some(value.parse::<u32>().map_err(|e| e.to_string())?),
+            "--a" => a = Some(value.parse::<i64>().map_err(|e| e.to_string())?),
+            "--b" => b = Some(value.parse::<i64>().map_err(|e| e.to_string())?),
+            other => return Err(format!("unknown flag `{}`", other)),
+        }
+    }
+
+    let usage = "usage: table --prime <p> --a <a> --b <b>";
+    addition_table(prime.ok_or(usage)?, a.ok_or(usage)?, b.ok_or(usage)?)
 }
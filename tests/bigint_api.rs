@@ -0,0 +1,199 @@
+// Compile test: the `Field`/`Point` API has to work for a field element that is not
+// `Copy`, which is the case for every big-integer backed field
+#![cfg(feature = "bigint")]
+
+use std::{fmt, ops};
+
+use cryptography::exercises::{
+    ec_point::{Field, Point},
+    finite_field::Fp,
+};
+use num_bigint::BigUint;
+
+#[derive(Clone, Debug, PartialEq)]
+struct BigFp {
+    num: BigUint,
+    modulus: BigUint,
+}
+
+impl BigFp {
+    fn new(num: u32, modulus: u32) -> Self {
+        Self {
+            num: BigUint::from(num % modulus),
+            modulus: BigUint::from(modulus),
+        }
+    }
+
+    fn with_num(&self, num: BigUint) -> Self {
+        Self {
+            num: num % &self.modulus,
+            modulus: self.modulus.clone(),
+        }
+    }
+}
+
+impl Field for BigFp {
+    fn zero(&self) -> Self {
+        self.with_num(BigUint::from(0_u32))
+    }
+
+    fn one(&self) -> Self {
+        self.with_num(BigUint::from(1_u32))
+    }
+
+    fn mul_inverse(&self) -> Self {
+        let exponent = &self.modulus - BigUint::from(2_u32);
+
+        self.with_num(self.num.modpow(&exponent, &self.modulus))
+    }
+
+    fn pow(&self, exp: u32) -> Self {
+        self.with_num(self.num.modpow(&BigUint::from(exp), &self.modulus))
+    }
+
+    fn add_inverse(&self) -> Self {
+        self.with_num(&self.modulus - &self.num)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num == BigUint::from(0_u32)
+    }
+
+    fn scalar_mul(&self, by: u32) -> Self {
+        self.with_num(&self.num * by)
+    }
+}
+
+impl fmt::Display for BigFp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} — 𝔽{}", self.num, self.modulus)
+    }
+}
+
+impl ops::Add for BigFp {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.with_num(&self.num + rhs.num)
+    }
+}
+
+impl ops::Sub for BigFp {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.with_num(&self.num + &self.modulus - rhs.num)
+    }
+}
+
+impl ops::Mul for BigFp {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.with_num(&self.num * rhs.num)
+    }
+}
+
+impl ops::Div for BigFp {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let rhs_inverse = rhs.mul_inverse();
+
+        self * rhs_inverse
+    }
+}
+
+impl ops::Neg for BigFp {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.add_inverse()
+    }
+}
+
+const PRIME: u32 = 223;
+
+// y² = x³ + 7 over 𝔽223, through the same point in both backends
+fn big_point(x: u32, y: u32) -> Point<BigFp> {
+    Point::new(
+        BigFp::new(0, PRIME),
+        BigFp::new(7, PRIME),
+        Some(BigFp::new(x, PRIME)),
+        Some(BigFp::new(y, PRIME)),
+    )
+    .unwrap()
+}
+
+fn small_point(x: u32, y: u32) -> Point<Fp> {
+    Point::new(
+        Fp::new(0, PRIME).unwrap(),
+        Fp::new(7, PRIME).unwrap(),
+        Some(Fp::new(x as i64, PRIME).unwrap()),
+        Some(Fp::new(y as i64, PRIME).unwrap()),
+    )
+    .unwrap()
+}
+
+fn same_point(big: &Point<BigFp>, small: &Point<Fp>) -> bool {
+    match (&big.x, &big.y, &small.x, &small.y) {
+        (Some(x1), Some(y1), Some(x2), Some(y2)) => {
+            x1.num == BigUint::from(x2.num) && y1.num == BigUint::from(y2.num)
+        }
+        (None, None, None, None) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test_point_api_without_copy() {
+    let point = big_point(47, 71);
+    let expected = small_point(47, 71);
+
+    assert!(!point.is_infinity());
+    assert!(Point::is_on_curve(
+        point.x.as_ref().unwrap(),
+        point.y.as_ref().unwrap(),
+        &point.a,
+        &point.b
+    ));
+
+    assert!(same_point(
+        &point.double().unwrap(),
+        &expected.double().unwrap()
+    ));
+
+    for by in [0, 1, 2, 7, 21, 22, 100] {
+        assert!(same_point(&point.scalar_mul(by), &expected.scalar_mul(by)));
+        assert!(same_point(
+            &point.scalar_mul_ladder(by),
+            &expected.scalar_mul(by)
+        ));
+    }
+
+    let x_only = Point::x_only_mul(
+        point.x.clone().unwrap(),
+        5,
+        point.a.clone(),
+        point.b.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        x_only.num,
+        BigUint::from(expected.scalar_mul(5).x.unwrap().num)
+    );
+}
+
+#[test]
+fn test_point_operators_without_copy() {
+    let point_a = big_point(47, 71);
+    let point_b = big_point(17, 56);
+
+    let sum = (point_a.clone() + point_b.clone()).unwrap();
+    let expected = (small_point(47, 71) + small_point(17, 56)).unwrap();
+    assert!(same_point(&sum, &expected));
+
+    assert_eq!((sum - point_b).unwrap(), point_a);
+    assert!((point_a.clone() + (-point_a)).unwrap().is_infinity());
+}
@@ -0,0 +1,72 @@
+use std::process::Command;
+
+fn run(args: &[&str]) -> (bool, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_cryptography"))
+        .args(args)
+        .output()
+        .unwrap();
+
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+fn cells(table: &str) -> Vec<Vec<String>> {
+    table
+        .lines()
+        .map(|line| {
+            line.split('|')
+                .map(|cell| cell.trim().to_string())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_table_dimensions() {
+    // y² = x³ + x + 1 over 𝔽5 has 8 affine points plus the point at infinity
+    let (success, stdout, _) = run(&["table", "--prime", "5", "--a", "1", "--b", "1"]);
+    let grid = cells(&stdout);
+
+    assert!(success);
+    assert_eq!(grid.len(), 10);
+    assert!(grid.iter().all(|row| row.len() == 10));
+}
+
+#[test]
+fn test_table_cells() {
+    let (_, stdout, _) = run(&["table", "--prime", "5", "--a", "1", "--b", "1"]);
+    let grid = cells(&stdout);
+
+    // header row and column
+    assert_eq!(grid[0][0], "+");
+    assert_eq!(grid[0][1], "∞");
+    assert_eq!(grid[2][0], "(0, 1)");
+    assert_eq!(grid[0][3], "(0, 4)");
+
+    // ∞ row/column is the identity
+    assert_eq!(grid[1][1], "∞");
+    assert_eq!(grid[1][2], "(0, 1)");
+    assert_eq!(grid[2][1], "(0, 1)");
+
+    // (0, 1) + (0, 1) = (4, 2); (0, 1) + (0, 4) = ∞; (2, 1) + (3, 1) = (0, 4)
+    assert_eq!(grid[2][2], "(4, 2)");
+    assert_eq!(grid[2][3], "∞");
+    assert_eq!(grid[4][6], "(0, 4)");
+}
+
+#[test]
+fn test_table_errors() {
+    let (success, _, stderr) = run(&["table", "--prime", "7", "--a", "0", "--b", "7"]);
+    assert!(!success);
+    assert_eq!(stderr.trim(), "error: y² = x³ + 0x + 0 is singular over 𝔽7");
+
+    let (success, _, stderr) = run(&["table", "--prime", "5", "--a", "1"]);
+    assert!(!success);
+    assert_eq!(
+        stderr.trim(),
+        "error: usage: table --prime <p> --a <a> --b <b>"
+    );
+}
@@ -0,0 +1,25 @@
+// Smoke tests for the programs under `examples/`, compiled in as modules so they run as
+// part of `cargo test`
+
+#![cfg(feature = "bigint")]
+
+#[path = "../examples/ecdsa.rs"]
+mod ecdsa;
+
+#[test]
+fn test_ecdsa_example() {
+    ecdsa::main();
+}
+
+#[test]
+fn test_ecdsa_example_encodings() {
+    let secret = ecdsa::scalar(23);
+    let public_key = ecdsa::generator().scalar_mul(secret.num);
+    let signature = ecdsa::sign(secret, ecdsa::toy_hash(b"Programming Bitcoin"), 42);
+
+    // P = (46, 109), y odd; r = 68 = 0x44, s = 14 = 0x0e
+    assert_eq!(
+        ecdsa::encode(&public_key, signature),
+        ("032e".to_string(), "300602014402010e".to_string())
+    );
+}
@@ -0,0 +1,135 @@
+// Serialization round trips over the JSON fixtures in tests/fixtures/serialization_vectors.json
+//
+// Each record is a secp256k1 keypair with its encodings, hex fields without 0x:
+//
+//     secret             the private key, 64 hex digits
+//     sec_compressed     the public key as SEC, 33 bytes
+//     sec_uncompressed   the public key as SEC, 65 bytes
+//     der                a DER signature made with the key
+//     address_payload    version byte || hash160(SEC), what `address` Base58Check encodes
+//     address            the P2PKH address of the key
+//     wif                the key in Wallet Import Format, exported with the
+//     compressed         (compressed, testnet) flags given as booleans
+//     testnet
+//     comment            where the record comes from, ignored
+//
+// Every decoder has to give back what its encoder was fed, and the encoders have to
+// reproduce the fixtures, which were computed independently of this crate
+#![cfg(feature = "bigint")]
+
+use cryptography::exercises::{
+    base58::{decode_check, encode_check},
+    ecdsa::{PrivateKey, Signature},
+    s256::S256Point,
+};
+use serde_json::Value;
+
+const FIXTURES: &str = "tests/fixtures/serialization_vectors.json";
+
+fn load(path: &str) -> Vec<Value> {
+    let path = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), path);
+    let json = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+
+    serde_json::from_str(&json).expect("an array of records")
+}
+
+fn string<'a>(record: &'a Value, name: &str, comment: &str) -> &'a str {
+    record
+        .get(name)
+        .and_then(Value::as_str)
+        .unwrap_or_else(|| panic!("{}: `{}` is not a string", comment, name))
+}
+
+fn bytes(record: &Value, name: &str, comment: &str) -> Vec<u8> {
+    hex::decode(string(record, name, comment))
+        .unwrap_or_else(|e| panic!("{}: `{}`: {}", comment, name, e))
+}
+
+fn flag(record: &Value, name: &str, comment: &str) -> bool {
+    record
+        .get(name)
+        .and_then(Value::as_bool)
+        .unwrap_or_else(|| panic!("{}: `{}` is not a boolean", comment, name))
+}
+
+#[test]
+fn test_serialization_round_trips() {
+    let records = load(FIXTURES);
+    assert!(!records.is_empty());
+
+    for (i, record) in records.iter().enumerate() {
+        let comment = &format!(
+            "record {} ({})",
+            i,
+            record.get("comment").and_then(Value::as_str).unwrap_or("")
+        );
+
+        let key = PrivateKey::from_hex(string(record, "secret", comment))
+            .unwrap_or_else(|e| panic!("{}: {}", comment, e));
+        let pubkey = key.public_key();
+
+        // SEC, both forms
+        for (compressed, name) in [(true, "sec_compressed"), (false, "sec_uncompressed")] {
+            let sec = bytes(record, name, comment);
+            assert_eq!(pubkey.to_sec(compressed), sec, "{}: {}", comment, name);
+
+            let parsed = S256Point::try_from(sec.as_slice())
+                .unwrap_or_else(|e| panic!("{}: {}: {}", comment, name, e));
+            assert_eq!(parsed, pubkey, "{}: {} parses", comment, name);
+            assert_eq!(
+                S256Point::try_from(pubkey.to_sec(compressed).as_slice()),
+                Ok(pubkey.clone()),
+                "{}: {} round trip",
+                comment,
+                name
+            );
+        }
+
+        // DER
+        let der = bytes(record, "der", comment);
+        let signature =
+            Signature::parse_der(&der).unwrap_or_else(|e| panic!("{}: der: {}", comment, e));
+        assert_eq!(signature.to_der().unwrap(), der, "{}: der", comment);
+        assert_eq!(
+            Signature::parse_der(&signature.to_der().unwrap()),
+            Ok(signature),
+            "{}: der round trip",
+            comment
+        );
+
+        // Base58Check
+        let payload = bytes(record, "address_payload", comment);
+        let address = string(record, "address", comment);
+        assert_eq!(encode_check(&payload), address, "{}: address", comment);
+        assert_eq!(
+            decode_check(address),
+            Ok(payload.clone()),
+            "{}: address",
+            comment
+        );
+        assert_eq!(
+            decode_check(&encode_check(&payload)),
+            Ok(payload),
+            "{}: Base58Check round trip",
+            comment
+        );
+
+        // WIF
+        let wif = string(record, "wif", comment);
+        let compressed = flag(record, "compressed", comment);
+        let testnet = flag(record, "testnet", comment);
+        assert_eq!(key.to_wif(compressed, testnet), wif, "{}: wif", comment);
+        assert_eq!(
+            PrivateKey::from_wif(wif),
+            Ok((key.clone(), compressed, testnet)),
+            "{}: wif",
+            comment
+        );
+        assert_eq!(
+            PrivateKey::from_wif(&key.to_wif(compressed, testnet)),
+            Ok((key, compressed, testnet)),
+            "{}: wif round trip",
+            comment
+        );
+    }
+}
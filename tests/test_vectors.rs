@@ -0,0 +1,114 @@
+// ECDSA over secp256k1 against the JSON fixtures in tests/fixtures/ecdsa_vectors.json
+//
+// Each record is an object with hex-encoded (no 0x, any length) fields:
+//
+//     z, r, s      the message hash and the signature, required
+//     pubkey_sec   the signer's public key, SEC compressed or uncompressed, required
+//     secret       the private key; when present the public key is derived from it
+//                  and (r, s) has to come out of signing
+//     k            the nonce the signature was made with; without it the RFC 6979
+//                  nonce is assumed
+//     comment      where the record comes from, ignored
+//
+// The fixtures were computed independently of this crate; they include the signing
+// and verification examples from Programming Bitcoin, chapter 3
+#![cfg(feature = "bigint")]
+
+use cryptography::exercises::{
+    ecdsa::{verify, PrivateKey, Signature},
+    s256::{S256Point, Secp256k1},
+};
+use num_bigint::BigUint;
+use serde_json::Value;
+
+const FIXTURES: &str = "tests/fixtures/ecdsa_vectors.json";
+
+struct Vector {
+    comment: String,
+    secret: Option<BigUint>,
+    k: Option<BigUint>,
+    z: BigUint,
+    signature: Signature,
+    pubkey_sec: Vec<u8>,
+}
+
+fn load(path: &str) -> Vec<Vector> {
+    let path = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), path);
+    let json = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    let records: Vec<Value> = serde_json::from_str(&json).expect("an array of records");
+
+    records
+        .iter()
+        .enumerate()
+        .map(|(i, record)| {
+            let field = |name: &str| -> Option<&str> {
+                record.get(name).map(|value| {
+                    value
+                        .as_str()
+                        .unwrap_or_else(|| panic!("record {}: `{}` is not a string", i, name))
+                })
+            };
+            let number = |name: &str| {
+                field(name).map(|hex| {
+                    BigUint::parse_bytes(hex.as_bytes(), 16)
+                        .unwrap_or_else(|| panic!("record {}: `{}` is not hex", i, name))
+                })
+            };
+            let required = |name: &str| {
+                number(name).unwrap_or_else(|| panic!("record {}: `{}` is missing", i, name))
+            };
+
+            Vector {
+                comment: format!("record {} ({})", i, field("comment").unwrap_or("")),
+                secret: number("secret"),
+                k: number("k"),
+                z: required("z"),
+                signature: Signature::new(required("r"), required("s")),
+                pubkey_sec: hex::decode(field("pubkey_sec").expect("`pubkey_sec` is missing"))
+                    .unwrap_or_else(|e| panic!("record {}: `pubkey_sec`: {}", i, e)),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_ecdsa_vectors() {
+    let vectors = load(FIXTURES);
+    assert!(!vectors.is_empty());
+
+    for vector in vectors {
+        let comment = &vector.comment;
+        let pubkey = S256Point::try_from(vector.pubkey_sec.as_slice())
+            .unwrap_or_else(|e| panic!("{}: {}", comment, e));
+
+        assert!(
+            verify::<Secp256k1>(&pubkey, &vector.z, &vector.signature),
+            "{}: does not verify",
+            comment
+        );
+        assert!(
+            !verify::<Secp256k1>(&pubkey, &(&vector.z + 1_u32), &vector.signature),
+            "{}: verifies another message",
+            comment
+        );
+
+        let Some(secret) = vector.secret else {
+            continue;
+        };
+        let private_key = PrivateKey::new(secret).unwrap();
+        let compressed = vector.pubkey_sec.len() == 33;
+
+        assert_eq!(
+            private_key.public_key().to_sec(compressed),
+            vector.pubkey_sec,
+            "{}: public key",
+            comment
+        );
+
+        let signature = match &vector.k {
+            Some(k) => private_key.sign_with_k(&vector.z, k).unwrap(),
+            None => private_key.sign(&vector.z),
+        };
+        assert_eq!(signature, vector.signature, "{}: signature", comment);
+    }
+}